@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Square root price limit overflow")]
+    SqrtPriceLimitOverflow,
+
+    #[msg("Tick index is out of range")]
+    TickOutOfRange,
+
+    #[msg("Tick must be a multiple of tick spacing")]
+    TickAndSpacingNotMatch,
+
+    #[msg("The tick for a limit order must not be the pool's current tick")]
+    LimitOrderOnCurrentTick,
+
+    #[msg("The limit order has not been filled by a price crossing yet")]
+    LimitOrderNotFilled,
+
+    #[msg("The limit order has already been closed")]
+    LimitOrderAlreadyClosed,
+
+    #[msg("Signer is not the owner of this limit order")]
+    LimitOrderOwnerMismatch,
+
+    #[msg("Liquidity sub delta underflow")]
+    LiquiditySubValueErr,
+
+    #[msg("Liquidity add delta overflow")]
+    LiquidityAddValueErr,
+
+    #[msg("Trade fee rate must be below the fee denominator, and at most half of it")]
+    FeeRateOverflow,
+
+    #[msg("Transfer amount is below the pool's configured dust threshold")]
+    AmountBelowMinimum,
+
+    #[msg("Every router hop after the first must be preceded by its own AmmConfig account")]
+    MissingHopAmmConfig,
+
+    #[msg("zero_for_one must have exactly one entry per router hop")]
+    SwapDirectionCountMismatch,
+}
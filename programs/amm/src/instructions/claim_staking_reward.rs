@@ -0,0 +1,86 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClaimStakingReward<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = reward_vault)]
+    pub pool_stake: Box<Account<'info, PoolStakeState>>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = position_stake.pool_id == pool_stake.pool_id,
+    )]
+    pub position_stake: Box<Account<'info, PositionStakeState>>,
+
+    #[account(mut)]
+    pub reward_vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// PDA signer authority over `reward_vault`, derived as
+    /// `[POOL_STAKE_SEED, pool_stake.pool_id, pool_stake.bump]`.
+    /// CHECK: verified by the `seeds`/`bump` constraint below, not read.
+    #[account(
+        seeds = [POOL_STAKE_SEED.as_bytes(), pool_stake.pool_id.as_ref()],
+        bump = pool_stake.bump,
+    )]
+    pub pool_stake_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays out emissions accrued since the position was staked (or last
+/// claimed), as a function of liquidity share of `total_staked_liquidity`,
+/// from the pool's reward vault. Swap fees are not touched here; they stay
+/// accrued on the underlying position and are not compounded into it.
+pub fn claim_staking_reward(ctx: Context<ClaimStakingReward>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool_stake = &mut ctx.accounts.pool_stake;
+
+    // `reward_growth_global_x64` is reward per unit of *currently* staked
+    // liquidity (a real stock, not a cumulative integral), so every staker
+    // present during an interval shares that interval's emission in
+    // proportion to their flat liquidity - a position staked since an
+    // earlier growth snapshot naturally collects every interval's delta
+    // since then on claim, which is what rewards duration without needing a
+    // separate duration-weighted accumulator.
+    pool_stake.settle_reward_growth(now);
+
+    let position_stake = &mut ctx.accounts.position_stake;
+
+    let growth_delta_x64 = pool_stake
+        .reward_growth_global_x64
+        .saturating_sub(position_stake.reward_growth_inside_last_x64);
+    position_stake.reward_growth_inside_last_x64 = pool_stake.reward_growth_global_x64;
+
+    // Only the share not yet vested out by a pending unstake still earns.
+    let earning_liquidity = position_stake
+        .liquidity
+        .saturating_sub(position_stake.withdrawal_liquidity_settled);
+    let reward_amount = (earning_liquidity.saturating_mul(growth_delta_x64) >> 64) as u64;
+
+    if reward_amount > 0 {
+        let pool_id = pool_stake.pool_id;
+        let bump = pool_stake.bump;
+        let authority_seeds: &[&[u8]] = &[POOL_STAKE_SEED.as_bytes(), pool_id.as_ref(), &[bump]];
+
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.reward_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_stake_authority.to_account_info(),
+                },
+                &[authority_seeds],
+            ),
+            reward_amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::error::ErrorCode;
+use crate::libraries::{limit_order_math, tick_math};
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CloseLimitOrder<'info> {
+    /// Must be `personal_limit_order.owner`; the NFT is only a transferable
+    /// receipt, closing rights always stay with the account on record.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    pub tick_array: AccountLoader<'info, TickArrayState>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = pool_id,
+        has_one = owner @ ErrorCode::LimitOrderOwnerMismatch,
+    )]
+    pub personal_limit_order: Box<Account<'info, PersonalLimitOrderState>>,
+
+    /// CHECK: validated against `personal_limit_order.pool_id` via `has_one`
+    pub pool_id: UncheckedAccount<'info>,
+
+    /// Receives the unfilled remainder of the deposited token.
+    #[account(mut)]
+    pub input_token_account: Box<Account<'info, TokenAccount>>,
+    /// Receives the filled output token.
+    #[account(mut)]
+    pub output_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub input_vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub output_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays out the unfilled portion of the deposit plus the filled output (converted
+/// at the order's tick's actual price, not 1:1) plus its pro-rated share of the
+/// trade fees collected from swappers who filled it, then closes the order. Can
+/// be called whether or not the order has filled at all; an order that never
+/// crossed simply returns its full original deposit.
+pub fn close_limit_order(ctx: Context<CloseLimitOrder>) -> Result<()> {
+    require!(!ctx.accounts.personal_limit_order.closed, ErrorCode::LimitOrderAlreadyClosed);
+
+    let order = &ctx.accounts.personal_limit_order;
+    let tick_array = ctx.accounts.tick_array.load()?;
+    let pool_state = ctx.accounts.pool_state.load()?;
+    let offset = tick_array.get_tick_offset_in_array(order.tick, pool_state.tick_spacing)?;
+    let tick_state = &tick_array.ticks[offset];
+    let amm_config = pool_state.amm_config;
+    let bump = pool_state.bump;
+    drop(pool_state);
+
+    let current_accum = tick_state.limit_filled_accum(order.zero_for_one);
+    let accum_delta = order.filled_fraction_x64(current_accum);
+
+    let unfilled = limit_order_math::unfilled_amount(order.amount_deposited, order.liquidity, accum_delta);
+    let filled_input = limit_order_math::filled_amount(order.liquidity, accum_delta);
+    let tick_sqrt_price_x64 = tick_math::get_sqrt_price_at_tick(order.tick)?;
+    let price_x64 = crate::libraries::full_math::mul_q64(tick_sqrt_price_x64, tick_sqrt_price_x64)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    let filled_output = if filled_input > 0 {
+        limit_order_math::convert_at_tick_price(filled_input, price_x64, order.zero_for_one)
+            .ok_or(error!(ErrorCode::LiquidityAddValueErr))?
+    } else {
+        0
+    };
+
+    // Fee growth is denominated in the same token the order is paid out in, so
+    // it's credited straight into `filled_output` rather than tracked separately.
+    let current_fee_growth = tick_state.limit_fee_growth(order.zero_for_one);
+    let fee_growth_delta = current_fee_growth.saturating_sub(order.fee_growth_inside_last_x64);
+    let fee_share = u64::try_from(order.liquidity.saturating_mul(fee_growth_delta) >> 64)
+        .map_err(|_| error!(ErrorCode::LiquidityAddValueErr))?;
+    let filled_output = filled_output.saturating_add(fee_share);
+    drop(tick_array);
+
+    ctx.accounts.personal_limit_order.closed = true;
+
+    let pool_signer_seeds: &[&[u8]] = &[POOL_SEED.as_bytes(), amm_config.as_ref(), &[bump]];
+
+    if unfilled > 0 {
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.input_vault.to_account_info(),
+                    to: ctx.accounts.input_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_state.to_account_info(),
+                },
+                &[pool_signer_seeds],
+            ),
+            unfilled,
+        )?;
+    }
+
+    if filled_output > 0 {
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: ctx.accounts.output_vault.to_account_info(),
+                    to: ctx.accounts.output_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_state.to_account_info(),
+                },
+                &[pool_signer_seeds],
+            ),
+            filled_output,
+        )?;
+    }
+
+    Ok(())
+}
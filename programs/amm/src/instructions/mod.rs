@@ -0,0 +1,21 @@
+pub mod open_limit_order;
+pub mod close_limit_order;
+pub mod quote_swap;
+pub mod quote_swap_router_base_in;
+pub mod set_pool_fee_rate;
+pub mod set_pool_min_swap_amount;
+pub mod stake_position;
+pub mod unstake_position;
+pub mod claim_staking_reward;
+pub mod swap;
+
+pub use open_limit_order::*;
+pub use close_limit_order::*;
+pub use quote_swap::*;
+pub use quote_swap_router_base_in::*;
+pub use set_pool_fee_rate::*;
+pub use set_pool_min_swap_amount::*;
+pub use stake_position::*;
+pub use unstake_position::*;
+pub use claim_staking_reward::*;
+pub use swap::*;
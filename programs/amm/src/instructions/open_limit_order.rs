@@ -0,0 +1,161 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(tick: i32, order_id: u64)]
+pub struct OpenLimitOrder<'info> {
+    /// Pays the rent for the new order and mint accounts.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Receives the NFT that represents ownership of this limit order.
+    pub order_owner: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The tick array covering `tick`; must already be initialized (e.g. by a
+    /// prior `open_position`).
+    #[account(mut)]
+    pub tick_array: AccountLoader<'info, TickArrayState>,
+
+    /// `order_id` is a caller-chosen nonce, unique per `(pool_state, tick,
+    /// order_owner)`, so more than one order can rest at the same tick
+    /// instead of the seed colliding on the second deposit.
+    #[account(
+        init,
+        seeds = [
+            LIMIT_ORDER_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            &tick.to_be_bytes(),
+            order_owner.key().as_ref(),
+            &order_id.to_be_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = PersonalLimitOrderState::LEN,
+    )]
+    pub personal_limit_order: Box<Account<'info, PersonalLimitOrderState>>,
+
+    /// One-of-one NFT representing ownership of this order, minted straight
+    /// to `order_owner`.
+    #[account(
+        init,
+        seeds = [LIMIT_ORDER_NFT_MINT_SEED.as_bytes(), personal_limit_order.key().as_ref()],
+        bump,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = pool_state,
+        mint::freeze_authority = pool_state,
+    )]
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = order_owner,
+    )]
+    pub nft_account: Box<Account<'info, TokenAccount>>,
+
+    /// The owner's token account for the side being deposited.
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The pool vault receiving the deposit.
+    #[account(mut)]
+    pub token_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits `amount` of a single token at `tick` as one-sided liquidity that
+/// fills completely, in one direction, the first time the pool's sqrt price
+/// strictly crosses `tick`. Rejects a tick equal to the pool's current tick,
+/// since a limit order placed exactly at the current price has no well defined
+/// fill direction until price actually moves off of it. Mints a one-of-one
+/// NFT to `order_owner` as a transferable receipt; `close_limit_order` always
+/// pays out to `order_owner` on record, regardless of who holds the NFT.
+pub fn open_limit_order(
+    ctx: Context<OpenLimitOrder>,
+    tick: i32,
+    order_id: u64,
+    zero_for_one: bool,
+    amount: u64,
+) -> Result<()> {
+    let pool_state = ctx.accounts.pool_state.load()?;
+    require!(
+        tick % i32::from(pool_state.tick_spacing) == 0,
+        ErrorCode::TickAndSpacingNotMatch
+    );
+    require!(tick != pool_state.tick_current, ErrorCode::LimitOrderOnCurrentTick);
+    let amm_config = pool_state.amm_config;
+    let bump = pool_state.bump;
+    drop(pool_state);
+
+    let liquidity = u128::from(amount);
+
+    {
+        let mut tick_array = ctx.accounts.tick_array.load_mut()?;
+        let pool_tick_spacing = ctx.accounts.pool_state.load()?.tick_spacing;
+        let offset = tick_array.get_tick_offset_in_array(tick, pool_tick_spacing)?;
+        let tick_state = &mut tick_array.ticks[offset];
+        tick_state.tick = tick;
+        if zero_for_one {
+            tick_state.limit_liquidity_token_1 = tick_state
+                .limit_liquidity_token_1
+                .checked_add(liquidity)
+                .ok_or(ErrorCode::LiquidityAddValueErr)?;
+        } else {
+            tick_state.limit_liquidity_token_0 = tick_state
+                .limit_liquidity_token_0
+                .checked_add(liquidity)
+                .ok_or(ErrorCode::LiquidityAddValueErr)?;
+        }
+
+        let order = &mut ctx.accounts.personal_limit_order;
+        order.owner = ctx.accounts.order_owner.key();
+        order.nft_mint = ctx.accounts.nft_mint.key();
+        order.pool_id = ctx.accounts.pool_state.key();
+        order.tick = tick;
+        order.zero_for_one = zero_for_one;
+        order.liquidity = liquidity;
+        order.amount_deposited = amount;
+        order.filled_accum_x64_at_open = tick_state.limit_filled_accum(zero_for_one);
+        order.fee_growth_inside_last_x64 = tick_state.limit_fee_growth(zero_for_one);
+        order.closed = false;
+    }
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.token_vault.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let pool_signer_seeds: &[&[u8]] = &[POOL_SEED.as_bytes(), amm_config.as_ref(), &[bump]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_account.to_account_info(),
+                authority: ctx.accounts.pool_state.to_account_info(),
+            },
+            &[pool_signer_seeds],
+        ),
+        1,
+    )?;
+
+    Ok(())
+}
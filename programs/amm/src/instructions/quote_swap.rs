@@ -0,0 +1,92 @@
+use crate::instructions::swap::{stable_swap_quote, traverse_readonly};
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(constraint = pool_state.load()?.amm_config == amm_config.key())]
+    pub pool_state: AccountLoader<'info, PoolState>,
+    // Remaining accounts: the tick arrays the traversal needs, in the same
+    // order `swap` expects them, read-only here since nothing is mutated.
+    // Unused (and may be omitted) when the pool is quoted off the stable
+    // curve instead.
+}
+
+/// Walks the same tick-array traversal as `instructions::swap` without
+/// mutating any account, returning the amount out/in, fee, and resulting
+/// price/tick via CPI return data. If the pool is in `CurveMode::Stable` and
+/// price sits within the configured band of the peg, quotes against the
+/// amplified StableSwap invariant instead, same as `swap` itself would - so
+/// a stable-mode pool's quote never disagrees with its actual execution.
+/// Fees are charged on the input side in this swap model, so `amount_out` is
+/// unaffected by `with_fees`; it selects whether the returned `amount_in` is
+/// the net, fee-inclusive amount the trader actually pays (what a real `swap`
+/// call would charge) or the gross pre-fee amount some price-impact
+/// calculations want instead.
+pub fn quote_swap<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, QuoteSwap<'info>>,
+    amount: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+    with_fees: bool,
+) -> Result<SwapQuoteResult> {
+    let pool_state = ctx.accounts.pool_state.load()?;
+    let sqrt_price_x64 = pool_state.sqrt_price_x64;
+    let tick_current = pool_state.tick_current;
+    let liquidity = pool_state.liquidity;
+    let tick_spacing = pool_state.tick_spacing;
+    let amp_coefficient = pool_state.amp_coefficient;
+    let is_stable = pool_state.should_use_stable_curve(sqrt_price_x64);
+    let fee_rate = pool_state.effective_trade_fee_rate(ctx.accounts.amm_config.trade_fee_rate);
+    drop(pool_state);
+
+    // `zero_for_one` isn't observable from `QuoteSwap`'s accounts (there are
+    // no vaults to compare against, unlike `swap`), so it is implied by
+    // whether the sqrt price limit sits below or above the pool's current
+    // price; a limit of 0 (no limit) defaults to quoting token_0 -> token_1.
+    let zero_for_one = sqrt_price_limit_x64 == 0 || sqrt_price_limit_x64 < sqrt_price_x64;
+
+    let (amount_in_gross, amount_out, fee_amount, sqrt_price_next_x64, tick) = if is_stable {
+        stable_swap_quote(
+            liquidity,
+            sqrt_price_x64,
+            amp_coefficient,
+            amount,
+            is_base_input,
+            zero_for_one,
+            fee_rate,
+        )?
+    } else {
+        traverse_readonly(
+            sqrt_price_x64,
+            tick_current,
+            liquidity,
+            tick_spacing,
+            ctx.remaining_accounts,
+            amount,
+            sqrt_price_limit_x64,
+            is_base_input,
+            zero_for_one,
+            fee_rate,
+        )?
+    };
+
+    // `amount_in`'s fee-inclusive total mirrors `compute_swap_step`, which
+    // deducts the fee from `amount_remaining` before sizing the trade;
+    // strip it back out when the caller wants the pre-fee amount instead.
+    let amount_in = if with_fees {
+        amount_in_gross
+    } else {
+        amount_in_gross.saturating_sub(fee_amount)
+    };
+
+    Ok(SwapQuoteResult {
+        amount_in,
+        amount_out,
+        fee_amount,
+        sqrt_price_x64: sqrt_price_next_x64,
+        tick,
+    })
+}
@@ -0,0 +1,137 @@
+use crate::error::ErrorCode;
+use crate::instructions::swap::{stable_swap_quote, traverse_readonly};
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+/// First hop of a router quote; mirrors the account shape `quote_swap` expects
+/// for a single pool. `ctx.remaining_accounts` carries the rest of the route:
+/// that first pool's tick arrays, then (for each further hop) an `AmmConfig`
+/// account for that hop's fee rate followed by its `pool_state` and that
+/// pool's own tick arrays, in hop order - the same layout `swap_router_base_in`
+/// would expect them in.
+#[derive(Accounts)]
+pub struct QuoteSwapRouterBaseIn<'info> {
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(constraint = pool_state.load()?.amm_config == amm_config.key())]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+/// Multi-hop counterpart to `quote_swap`: chains a base-input quote through
+/// each pool in the route, feeding the previous hop's `amount_out` in as the
+/// next hop's input amount, and returns the aggregate `SwapQuoteResult`
+/// (`sqrt_price_x64`/`tick` reflect the final hop's pool; `fee_amount` sums
+/// every hop's fee, each in that hop's input token). `amount_in` is in the
+/// first hop's input token, so `with_fees` set to false strips only the
+/// first hop's fee from it, same as a single `quote_swap` call on that pool.
+/// Each hop whose pool is in `CurveMode::Stable` and within its peg band is
+/// quoted against the amplified StableSwap invariant instead of the tick
+/// walk, same as `swap` would price it.
+///
+/// Hop boundaries within `ctx.remaining_accounts` aren't given a count up
+/// front, so they're found the same way Anchor itself tells accounts apart:
+/// a `PoolState` account starts a new hop; an `AmmConfig` account immediately
+/// preceding it supplies that hop's fee rate (the first hop instead uses
+/// `ctx.accounts.amm_config`, same as `quote_swap`); anything else is another
+/// tick array belonging to the current hop.
+///
+/// `zero_for_one` gives each hop's real swap direction explicitly - one entry
+/// per hop, in hop order - since it can't be inferred from a bare
+/// `pool_state` account the way `swap` infers it from its input vault.
+pub fn quote_swap_router_base_in<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, QuoteSwapRouterBaseIn<'info>>,
+    amount_in: u64,
+    zero_for_one: Vec<bool>,
+    with_fees: bool,
+) -> Result<SwapQuoteResult> {
+    let mut hops: Vec<(AccountLoader<'info, PoolState>, Vec<AccountInfo<'info>>, Option<u32>)> =
+        vec![(ctx.accounts.pool_state.clone(), Vec::new(), None)];
+
+    let mut pending_hop_trade_fee_rate: Option<u32> = None;
+    for account_info in ctx.remaining_accounts.iter() {
+        match AccountLoader::<PoolState>::try_from(account_info) {
+            Ok(next_pool) => hops.push((next_pool, Vec::new(), pending_hop_trade_fee_rate.take())),
+            Err(_) => match Account::<AmmConfig>::try_from(account_info) {
+                Ok(next_amm_config) => pending_hop_trade_fee_rate = Some(next_amm_config.trade_fee_rate),
+                Err(_) => hops.last_mut().unwrap().1.push(account_info.clone()),
+            },
+        }
+    }
+
+    require_eq!(zero_for_one.len(), hops.len(), ErrorCode::SwapDirectionCountMismatch);
+
+    let mut amount_remaining = amount_in;
+    let mut total_fee_amount: u64 = 0;
+    let mut first_hop_fee_amount: u64 = 0;
+    let mut final_sqrt_price_x64 = 0u128;
+    let mut final_tick = 0i32;
+
+    for (hop_index, (pool_state_loader, tick_arrays, hop_trade_fee_rate)) in hops.iter().enumerate() {
+        let pool_state = pool_state_loader.load()?;
+        let sqrt_price_x64 = pool_state.sqrt_price_x64;
+        let tick_current = pool_state.tick_current;
+        let liquidity = pool_state.liquidity;
+        let tick_spacing = pool_state.tick_spacing;
+        let amp_coefficient = pool_state.amp_coefficient;
+        let is_stable = pool_state.should_use_stable_curve(sqrt_price_x64);
+
+        let amm_config_trade_fee_rate = if hop_index == 0 {
+            ctx.accounts.amm_config.trade_fee_rate
+        } else {
+            hop_trade_fee_rate.ok_or(error!(ErrorCode::MissingHopAmmConfig))?
+        };
+        let fee_rate = pool_state.effective_trade_fee_rate(amm_config_trade_fee_rate);
+        drop(pool_state);
+
+        let hop_zero_for_one = zero_for_one[hop_index];
+
+        let (hop_amount_in, hop_amount_out, hop_fee_amount, hop_sqrt_price_x64, hop_tick) = if is_stable {
+            stable_swap_quote(
+                liquidity,
+                sqrt_price_x64,
+                amp_coefficient,
+                amount_remaining,
+                true,
+                hop_zero_for_one,
+                fee_rate,
+            )?
+        } else {
+            traverse_readonly(
+                sqrt_price_x64,
+                tick_current,
+                liquidity,
+                tick_spacing,
+                tick_arrays,
+                amount_remaining,
+                0,
+                true,
+                hop_zero_for_one,
+                fee_rate,
+            )?
+        };
+
+        total_fee_amount = total_fee_amount.saturating_add(hop_fee_amount);
+        if hop_index == 0 {
+            first_hop_fee_amount = hop_fee_amount;
+        }
+        amount_remaining = hop_amount_out;
+        final_sqrt_price_x64 = hop_sqrt_price_x64;
+        final_tick = hop_tick;
+        let _ = hop_amount_in;
+    }
+
+    let amount_out = amount_remaining;
+    let amount_in_reported = if with_fees {
+        amount_in
+    } else {
+        amount_in.saturating_sub(first_hop_fee_amount)
+    };
+
+    Ok(SwapQuoteResult {
+        amount_in: amount_in_reported,
+        amount_out,
+        fee_amount: total_fee_amount,
+        sqrt_price_x64: final_sqrt_price_x64,
+        tick: final_tick,
+    })
+}
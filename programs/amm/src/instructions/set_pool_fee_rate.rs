@@ -0,0 +1,38 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPoolFeeRate<'info> {
+    /// Must be the owner of `amm_config`, or the protocol admin.
+    pub owner: Signer<'info>,
+
+    #[account(constraint = amm_config.owner == owner.key() || owner.key() == crate::admin::id())]
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(mut, constraint = pool_state.load()?.amm_config == amm_config.key())]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+/// Overrides the trade fee rate for a single pool instead of the whole
+/// `amm_config`. Before applying the override, snapshots the pool's current
+/// fee growth globals into `fee_growth_global_0/1_x64_at_last_rate_change` so
+/// the split between fee income earned under the old rate and under the new
+/// one stays reconstructable, then applies the new rate so it only ever
+/// takes effect on volume that trades after this instruction lands.
+pub fn set_pool_fee_rate(ctx: Context<SetPoolFeeRate>, trade_fee_rate: u32) -> Result<()> {
+    require_gt!(FEE_RATE_DENOMINATOR_VALUE, trade_fee_rate, ErrorCode::FeeRateOverflow);
+    require!(
+        trade_fee_rate <= FEE_RATE_DENOMINATOR_VALUE / 2,
+        ErrorCode::FeeRateOverflow
+    );
+
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.fee_growth_global_0_x64_at_last_rate_change = pool_state.fee_growth_global_0_x64;
+    pool_state.fee_growth_global_1_x64_at_last_rate_change = pool_state.fee_growth_global_1_x64;
+
+    pool_state.trade_fee_rate_override = trade_fee_rate;
+    pool_state.trade_fee_rate_override_set = true;
+
+    Ok(())
+}
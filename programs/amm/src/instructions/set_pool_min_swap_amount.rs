@@ -0,0 +1,36 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPoolMinSwapAmount<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(has_one = owner)]
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(mut, constraint = pool_state.load()?.amm_config == amm_config.key())]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+/// Sets the dust thresholds `swap` checks resulting token transfers against
+/// (via `PoolState::check_min_swap_amount`, now wired into `instructions::swap`).
+/// `increase_liquidity`/`decrease_liquidity` have no implementation anywhere
+/// in this tree to wire the same check into - there's no
+/// `PersonalPositionState`/`ProtocolPositionState` or liquidity-management
+/// instruction file backing the entries `lib.rs`'s `#[program]` module
+/// already declares for them - so this doc comment no longer claims they
+/// check it until that subsystem actually exists. Owner sets these
+/// thresholds in each token's smallest unit, so they're meaningful
+/// regardless of the pair's relative decimals (e.g. a 6-decimal/9-decimal
+/// pair needs different raw thresholds to represent the same dollar dust
+/// amount on each side).
+pub fn set_pool_min_swap_amount(
+    ctx: Context<SetPoolMinSwapAmount>,
+    min_swap_amount_0: u64,
+    min_swap_amount_1: u64,
+) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.min_swap_amount_0 = min_swap_amount_0;
+    pool_state.min_swap_amount_1 = min_swap_amount_1;
+    Ok(())
+}
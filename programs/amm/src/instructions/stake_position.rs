@@ -0,0 +1,71 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct StakePosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_stake: Box<Account<'info, PoolStakeState>>,
+
+    /// The personal position being staked; liquidity is read, not modified.
+    pub personal_position: Box<Account<'info, PersonalPositionState>>,
+
+    #[account(
+        init,
+        seeds = [STAKE_SEED.as_bytes(), personal_position.key().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + std::mem::size_of::<PositionStakeState>(),
+    )]
+    pub position_stake: Box<Account<'info, PositionStakeState>>,
+
+    /// The owner's account holding the position NFT.
+    #[account(mut)]
+    pub nft_account: Box<Account<'info, TokenAccount>>,
+    /// Pool-owned vault the NFT moves into while staked.
+    #[account(mut)]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfers a position NFT into the pool's staking vault and starts duration
+/// weighting its liquidity for reward accrual. Swap fees stay accrued on the
+/// underlying position and are not compounded into it; this only layers
+/// emissions on top.
+pub fn stake_position(ctx: Context<StakePosition>) -> Result<()> {
+    let liquidity = ctx.accounts.personal_position.liquidity;
+    let now = Clock::get()?.unix_timestamp;
+
+    let pool_stake = &mut ctx.accounts.pool_stake;
+    pool_stake.settle_reward_growth(now);
+    pool_stake.total_staked_liquidity = pool_stake.total_staked_liquidity.saturating_add(liquidity);
+
+    let position_stake = &mut ctx.accounts.position_stake;
+    position_stake.nft_mint = ctx.accounts.personal_position.nft_mint;
+    position_stake.pool_id = pool_stake.pool_id;
+    position_stake.owner = ctx.accounts.owner.key();
+    position_stake.liquidity = liquidity;
+    position_stake.staked_at = now;
+    position_stake.reward_growth_inside_last_x64 = pool_stake.reward_growth_global_x64;
+    position_stake.unstake_requested = false;
+    position_stake.withdrawal_liquidity_settled = 0;
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.nft_account.to_account_info(),
+                to: ctx.accounts.nft_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,661 @@
+use crate::error::ErrorCode;
+use crate::libraries::{full_math, limit_order_math, liquidity_math, stable_curve, swap_math, tick_math};
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct SwapSingle<'info> {
+    pub payer: Signer<'info>,
+
+    pub amm_config: Account<'info, AmmConfig>,
+
+    #[account(mut, constraint = pool_state.load()?.amm_config == amm_config.key())]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    #[account(mut)]
+    pub input_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub output_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, constraint = input_vault.key() == pool_state.load()?.token_vault_0 || input_vault.key() == pool_state.load()?.token_vault_1)]
+    pub input_vault: Box<Account<'info, TokenAccount>>,
+    #[account(mut, constraint = output_vault.key() == pool_state.load()?.token_vault_0 || output_vault.key() == pool_state.load()?.token_vault_1)]
+    pub output_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    // Remaining accounts: the tick arrays needed for the traversal, in
+    // walk order starting from the array containing the pool's current tick.
+}
+
+/// Swaps one token for as much as possible of another token across a single
+/// pool. Walks tick arrays one at a time, crossing initialized ticks (range
+/// liquidity via `liquidity_net`, limit-order liquidity by advancing its
+/// fill accumulator) until `amount` is exhausted or `sqrt_price_limit_x64` is
+/// reached. If the pool is in `CurveMode::Stable` and price sits within the
+/// configured band of the peg, prices the whole trade against the amplified
+/// StableSwap invariant instead and skips the tick walk entirely.
+pub fn swap<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapSingle<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+) -> Result<()> {
+    let zero_for_one = ctx.accounts.input_vault.key() == ctx.accounts.pool_state.load()?.token_vault_0;
+
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    let fee_rate = pool_state.effective_trade_fee_rate(ctx.accounts.amm_config.trade_fee_rate);
+
+    let (amount_in, amount_out) = if pool_state.should_use_stable_curve(pool_state.sqrt_price_x64) {
+        stable_swap_step(&mut pool_state, amount, is_base_input, zero_for_one, fee_rate)?
+    } else {
+        concentrated_swap_loop(
+            &mut pool_state,
+            ctx.remaining_accounts,
+            amount,
+            sqrt_price_limit_x64,
+            is_base_input,
+            zero_for_one,
+            fee_rate,
+        )?
+    };
+
+    // `amount` is base input or base output depending on `is_base_input`; the
+    // threshold guards whichever side of the trade wasn't pinned by `amount`.
+    if is_base_input {
+        require_gte!(amount_out, other_amount_threshold, ErrorCode::AmountBelowMinimum);
+    } else {
+        require_gte!(other_amount_threshold, amount_in, ErrorCode::AmountBelowMinimum);
+    }
+    let amount_to_transfer_in = amount_in;
+    let amount_to_transfer_out = amount_out;
+
+    let (check_0, check_1) = if zero_for_one {
+        (amount_to_transfer_in, amount_to_transfer_out)
+    } else {
+        (amount_to_transfer_out, amount_to_transfer_in)
+    };
+    pool_state.check_min_swap_amount(check_0, check_1)?;
+    drop(pool_state);
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.input_token_account.to_account_info(),
+                to: ctx.accounts.input_vault.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        amount_to_transfer_in,
+    )?;
+
+    let amm_config_key = ctx.accounts.amm_config.key();
+    let bump = ctx.accounts.pool_state.load()?.bump;
+    let pool_signer_seeds: &[&[u8]] = &[POOL_SEED.as_bytes(), amm_config_key.as_ref(), &[bump]];
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.output_vault.to_account_info(),
+                to: ctx.accounts.output_token_account.to_account_info(),
+                authority: ctx.accounts.pool_state.to_account_info(),
+            },
+            &[pool_signer_seeds],
+        ),
+        amount_to_transfer_out,
+    )?;
+
+    Ok(())
+}
+
+/// Result of filling resting limit-order liquidity at a single tick against
+/// a swapper's remaining budget. All amounts are real: `consumed_output` is
+/// bounded by the swapper's actual remaining budget, not the order's full
+/// size, so a tick with limit liquidity but an exhausted (or absent)
+/// swapper budget correctly fills for nothing.
+struct LimitFill {
+    /// Amount of the tick's limit liquidity actually filled (same units as
+    /// `TickState::limit_liquidity`), used to pro-rate `filled_ratio_x64`.
+    consumed_output: u128,
+    /// Total the swapper pays for this fill, principal plus fee.
+    amount_in: u64,
+    /// Total paid to the swapper for this fill.
+    amount_out: u64,
+    /// Fee revenue earned by (and owed back to) the limit liquidity that
+    /// filled, in the swapper's input token (the token the filled orders are
+    /// paid out in), not the token of `amount_out`/`consumed_output`.
+    limit_fee_amount: u128,
+    /// However much of `amount_remaining` this fill used up, in whichever
+    /// side `amount_remaining` is denominated in (`amount_in` if
+    /// `is_base_input`, `amount_out` otherwise).
+    amount_remaining_consumed: u64,
+}
+
+/// Fills up to `limit_liquidity` of a tick's resting limit-order liquidity
+/// against a swapper's remaining budget, charging `fee_rate` same as a
+/// regular swap step would. `limit_liquidity` is denominated in the token
+/// the swapper receives (see `TickState::limit_liquidity`), so it's compared
+/// directly against `consumed_output`; the fee is charged on the input side,
+/// in the token the filled orders are paid out in.
+fn fill_limit_liquidity(
+    limit_liquidity: u128,
+    tick_sqrt_price: u128,
+    amount_remaining: u64,
+    is_base_input: bool,
+    zero_for_one: bool,
+    fee_rate: u32,
+) -> Result<LimitFill> {
+    const FEE_RATE_DENOMINATOR_VALUE: u128 = 1_000_000;
+
+    let price_x64 = full_math::mul_q64(tick_sqrt_price, tick_sqrt_price)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+
+    // How much of this tick's resting (output-side) limit liquidity the
+    // swapper's remaining budget could absorb, ignoring the limit itself.
+    let output_budget = if is_base_input {
+        // `amount_remaining` is input-side; the fee comes off the top same
+        // as a regular swap step, so only the net portion buys output.
+        let net_remaining = full_math::mul_div_floor(
+            amount_remaining as u128,
+            (FEE_RATE_DENOMINATOR_VALUE as u128)
+                .checked_sub(fee_rate as u128)
+                .ok_or(error!(ErrorCode::FeeRateOverflow))?,
+            FEE_RATE_DENOMINATOR_VALUE,
+        )
+        .ok_or(error!(ErrorCode::FeeRateOverflow))?;
+        limit_order_math::convert_at_tick_price(net_remaining, price_x64, !zero_for_one)
+            .ok_or(error!(ErrorCode::LiquidityAddValueErr))? as u128
+    } else {
+        amount_remaining as u128
+    };
+
+    let consumed_output = output_budget.min(limit_liquidity);
+    if consumed_output == 0 {
+        return Ok(LimitFill {
+            consumed_output: 0,
+            amount_in: 0,
+            amount_out: 0,
+            limit_fee_amount: 0,
+            amount_remaining_consumed: 0,
+        });
+    }
+
+    let net_amount_in = limit_order_math::convert_at_tick_price(consumed_output, price_x64, zero_for_one)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))? as u128;
+
+    let limit_fee_amount = full_math::mul_div_ceil(
+        net_amount_in,
+        fee_rate as u128,
+        (FEE_RATE_DENOMINATOR_VALUE as u128)
+            .checked_sub(fee_rate as u128)
+            .ok_or(error!(ErrorCode::FeeRateOverflow))?,
+    )
+    .ok_or(error!(ErrorCode::FeeRateOverflow))?;
+
+    let amount_in = u64::try_from(net_amount_in.saturating_add(limit_fee_amount))
+        .map_err(|_| error!(ErrorCode::LiquidityAddValueErr))?;
+    let amount_out = u64::try_from(consumed_output).map_err(|_| error!(ErrorCode::LiquidityAddValueErr))?;
+    let amount_remaining_consumed = if is_base_input { amount_in } else { amount_out };
+
+    Ok(LimitFill {
+        consumed_output,
+        amount_in,
+        amount_out,
+        limit_fee_amount,
+        amount_remaining_consumed,
+    })
+}
+
+/// The concentrated-liquidity tick walk shared by `swap` and, read-only, by
+/// `quote_swap`/`quote_swap_router_base_in` (see `traverse_readonly` below,
+/// which mirrors this loop
+/// against local copies instead of `&mut pool_state`/`&mut tick_array`).
+pub fn concentrated_swap_loop<'info>(
+    pool_state: &mut std::cell::RefMut<PoolState>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+    zero_for_one: bool,
+    fee_rate: u32,
+) -> Result<(u64, u64)> {
+    let tick_spacing = pool_state.tick_spacing;
+    let mut sqrt_price_x64 = pool_state.sqrt_price_x64;
+    let mut tick = pool_state.tick_current;
+    let mut liquidity = pool_state.liquidity;
+
+    let mut amount_remaining = amount;
+    let mut amount_in_total: u64 = 0;
+    let mut amount_out_total: u64 = 0;
+    let mut fee_growth_global_delta_x64: u128 = 0;
+
+    let sqrt_price_limit_x64 = if sqrt_price_limit_x64 == 0 {
+        if zero_for_one {
+            tick_math::get_sqrt_price_at_tick(tick_math::MIN_TICK)?
+        } else {
+            tick_math::get_sqrt_price_at_tick(tick_math::MAX_TICK)?
+        }
+    } else {
+        sqrt_price_limit_x64
+    };
+
+    for tick_array_info in remaining_accounts {
+        if amount_remaining == 0 {
+            break;
+        }
+        let tick_array_loader: AccountLoader<TickArrayState> = AccountLoader::try_from(tick_array_info)?;
+        let mut tick_array = tick_array_loader.load_mut()?;
+
+        loop {
+            if amount_remaining == 0 {
+                break;
+            }
+            let offset = match tick_array.next_initialized_tick_offset(tick, tick_spacing, zero_for_one) {
+                Some(o) => o,
+                None => break,
+            };
+            let tick_state = &mut tick_array.ticks[offset];
+            let tick_sqrt_price = tick_math::get_sqrt_price_at_tick(tick_state.tick)?;
+
+            let reached_limit = zero_for_one && tick_sqrt_price < sqrt_price_limit_x64
+                || !zero_for_one && tick_sqrt_price > sqrt_price_limit_x64;
+            let sqrt_price_target = if reached_limit { sqrt_price_limit_x64 } else { tick_sqrt_price };
+
+            let step = swap_math::compute_swap_step(
+                sqrt_price_x64,
+                sqrt_price_target,
+                liquidity,
+                amount_remaining,
+                fee_rate,
+                is_base_input,
+            )?;
+
+            sqrt_price_x64 = step.sqrt_price_next_x64;
+            amount_in_total = amount_in_total.saturating_add(step.amount_in).saturating_add(step.fee_amount);
+            amount_out_total = amount_out_total.saturating_add(step.amount_out);
+            if liquidity > 0 {
+                fee_growth_global_delta_x64 = fee_growth_global_delta_x64
+                    .saturating_add((step.fee_amount as u128).saturating_mul(1u128 << 64) / liquidity);
+            }
+            amount_remaining = if is_base_input {
+                amount_remaining.saturating_sub(step.amount_in + step.fee_amount)
+            } else {
+                amount_remaining.saturating_sub(step.amount_out)
+            };
+
+            if sqrt_price_x64 == tick_sqrt_price && !reached_limit {
+                // Crossed the tick: fold in range liquidity_net and fill
+                // whatever resting limit liquidity the swapper's remaining
+                // budget can actually afford.
+                let liquidity_net = if zero_for_one { -tick_state.liquidity_net } else { tick_state.liquidity_net };
+                liquidity = liquidity_math::add_delta(liquidity, liquidity_net)?;
+
+                let limit_liquidity = tick_state.limit_liquidity(zero_for_one);
+                if limit_liquidity > 0 && amount_remaining > 0 {
+                    let fill = fill_limit_liquidity(
+                        limit_liquidity,
+                        tick_sqrt_price,
+                        amount_remaining,
+                        is_base_input,
+                        zero_for_one,
+                        fee_rate,
+                    )?;
+
+                    if fill.consumed_output > 0 {
+                        amount_in_total = amount_in_total.saturating_add(fill.amount_in);
+                        amount_out_total = amount_out_total.saturating_add(fill.amount_out);
+                        amount_remaining = amount_remaining.saturating_sub(fill.amount_remaining_consumed);
+
+                        let limit_fee_growth_delta_x64 =
+                            (fill.limit_fee_amount.saturating_mul(1u128 << 64)) / limit_liquidity;
+                        let new_limit_fee_growth =
+                            tick_state.limit_fee_growth(zero_for_one).saturating_add(limit_fee_growth_delta_x64);
+                        if zero_for_one {
+                            tick_state.limit_fee_growth_0_x64 = new_limit_fee_growth;
+                        } else {
+                            tick_state.limit_fee_growth_1_x64 = new_limit_fee_growth;
+                        }
+
+                        let filled_ratio_x64 = (fill.consumed_output.saturating_mul(1u128 << 64)) / limit_liquidity;
+                        let new_accum = limit_order_math::advance_filled_accum(
+                            tick_state.limit_filled_accum(zero_for_one),
+                            filled_ratio_x64,
+                        );
+                        if zero_for_one {
+                            tick_state.limit_order_filled_accum_1_x64 = new_accum;
+                        } else {
+                            tick_state.limit_order_filled_accum_0_x64 = new_accum;
+                        }
+                    }
+                }
+
+                tick = if zero_for_one { tick_state.tick - 1 } else { tick_state.tick };
+            } else {
+                tick = tick_math::get_tick_at_sqrt_price(sqrt_price_x64)?;
+                break;
+            }
+
+            if reached_limit {
+                break;
+            }
+        }
+        if sqrt_price_x64 == sqrt_price_limit_x64 {
+            break;
+        }
+    }
+
+    pool_state.sqrt_price_x64 = sqrt_price_x64;
+    pool_state.tick_current = tick;
+    pool_state.liquidity = liquidity;
+    // `fee_growth_global_delta_x64` accumulated each step's fee, scaled to a
+    // per-unit-liquidity Q64.64 growth against the liquidity active during
+    // that step; credit it to whichever side was paid in.
+    if zero_for_one {
+        pool_state.fee_growth_global_0_x64 =
+            pool_state.fee_growth_global_0_x64.saturating_add(fee_growth_global_delta_x64);
+    } else {
+        pool_state.fee_growth_global_1_x64 =
+            pool_state.fee_growth_global_1_x64.saturating_add(fee_growth_global_delta_x64);
+    }
+
+    Ok((amount_in_total, amount_out_total))
+}
+
+/// Result of pricing a trade against the amplified StableSwap invariant,
+/// shared by the mutating `stable_swap_step` and the read-only
+/// `stable_swap_quote` (used by `quote_swap`/`quote_swap_router_base_in`) so
+/// a quote can never disagree with the swap it quotes.
+pub struct StableSwapResult {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub final_reserves: [u128; 2],
+}
+
+/// Prices a trade against the StableSwap invariant given the pool's current
+/// virtual `reserves` (`reserve_0 = L / sqrtP`, `reserve_1 = L * sqrtP`).
+/// Charges `fee_rate` against the input side, same split
+/// `swap_math::compute_swap_step` uses for the concentrated path - only the
+/// post-fee amount moves the reserve the curve is solved against. Honors
+/// `is_base_input`, solving `compute_y` for whichever side `amount` doesn't
+/// pin. Pure function; callers are responsible for turning `final_reserves`
+/// back into a `sqrt_price_x64`/`liquidity` and for crediting `fee_amount` to
+/// `fee_growth_global_0/1_x64`.
+pub fn compute_stable_swap(
+    reserves: [u128; 2],
+    amp_coefficient: u64,
+    amount: u64,
+    is_base_input: bool,
+    zero_for_one: bool,
+    fee_rate: u32,
+) -> Result<StableSwapResult> {
+    const FEE_RATE_DENOMINATOR_VALUE: u128 = 1_000_000;
+
+    let d = stable_curve::compute_d(reserves, amp_coefficient).ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+
+    let (in_index, out_index) = if zero_for_one { (0usize, 1usize) } else { (1usize, 0usize) };
+
+    // `net_amount_in`/`fee_amount` split the swapper's real, fee-inclusive
+    // transfer into the part that actually moves the virtual reserve (and
+    // prices against the curve) and the part credited straight to
+    // `fee_growth_global`, mirroring how `compute_swap_step` keeps fee
+    // revenue out of the liquidity used for pricing.
+    let (net_amount_in, fee_amount, amount_out) = if is_base_input {
+        let net_amount_in = full_math::mul_div_floor(
+            amount as u128,
+            (FEE_RATE_DENOMINATOR_VALUE as u128)
+                .checked_sub(fee_rate as u128)
+                .ok_or(error!(ErrorCode::FeeRateOverflow))?,
+            FEE_RATE_DENOMINATOR_VALUE,
+        )
+        .ok_or(error!(ErrorCode::FeeRateOverflow))?;
+        let fee_amount = (amount as u128).saturating_sub(net_amount_in);
+
+        let mut amounts = reserves;
+        amounts[in_index] = reserves[in_index].saturating_add(net_amount_in);
+        let new_out_reserve = stable_curve::compute_y(amounts, amp_coefficient, out_index, d)
+            .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+        let amount_out = reserves[out_index].saturating_sub(new_out_reserve);
+
+        (net_amount_in, fee_amount, amount_out)
+    } else {
+        let amount_out = amount as u128;
+        require_gt!(reserves[out_index], amount_out, ErrorCode::LiquidityAddValueErr);
+
+        let mut amounts = reserves;
+        amounts[out_index] = reserves[out_index].saturating_sub(amount_out);
+        let new_in_reserve = stable_curve::compute_y(amounts, amp_coefficient, in_index, d)
+            .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+        let net_amount_in = new_in_reserve.saturating_sub(reserves[in_index]);
+
+        let fee_amount = full_math::mul_div_ceil(
+            net_amount_in,
+            fee_rate as u128,
+            (FEE_RATE_DENOMINATOR_VALUE as u128)
+                .checked_sub(fee_rate as u128)
+                .ok_or(error!(ErrorCode::FeeRateOverflow))?,
+        )
+        .ok_or(error!(ErrorCode::FeeRateOverflow))?;
+
+        (net_amount_in, fee_amount, amount_out)
+    };
+
+    let mut final_reserves = reserves;
+    final_reserves[in_index] = reserves[in_index].saturating_add(net_amount_in);
+    final_reserves[out_index] = reserves[out_index].saturating_sub(amount_out);
+
+    let amount_in_total = net_amount_in.saturating_add(fee_amount);
+    Ok(StableSwapResult {
+        amount_in: u64::try_from(amount_in_total).map_err(|_| error!(ErrorCode::LiquidityAddValueErr))?,
+        amount_out: u64::try_from(amount_out).map_err(|_| error!(ErrorCode::LiquidityAddValueErr))?,
+        fee_amount: u64::try_from(fee_amount).map_err(|_| error!(ErrorCode::LiquidityAddValueErr))?,
+        final_reserves,
+    })
+}
+
+/// Pool's current virtual reserves, implied by its concentrated-liquidity
+/// state: `reserve_0 = L / sqrtP`, `reserve_1 = L * sqrtP`.
+fn virtual_reserves(liquidity: u128, sqrt_price_x64: u128) -> Result<[u128; 2]> {
+    let reserve_0 = full_math::mul_div_floor(liquidity, 1u128 << 64, sqrt_price_x64)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    let reserve_1 =
+        full_math::mul_div_floor(liquidity, sqrt_price_x64, 1u128 << 64).ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    Ok([reserve_0, reserve_1])
+}
+
+/// A stable-curve trade's virtual reserves moved the pool off its old
+/// `x*y=L^2` curve; re-derive `sqrt_price_x64`/`liquidity` from the post-trade
+/// reserves so a later concentrated-mode swap (price having since left the
+/// peg band) resumes from values actually consistent with them.
+fn sqrt_price_and_liquidity_from_reserves(final_reserves: [u128; 2]) -> Result<(u128, u128)> {
+    let price_scaled_by_2_64 = full_math::mul_div_floor(final_reserves[1], 1u128 << 64, final_reserves[0])
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    let sqrt_price_x64 = full_math::sqrt_u128(price_scaled_by_2_64).saturating_mul(1u128 << 32);
+    let liquidity = full_math::sqrt_u128(
+        final_reserves[0]
+            .checked_mul(final_reserves[1])
+            .ok_or(error!(ErrorCode::LiquidityAddValueErr))?,
+    );
+    Ok((sqrt_price_x64, liquidity))
+}
+
+/// Prices the whole trade against the amplified StableSwap invariant using
+/// virtual reserves implied by the pool's current concentrated-liquidity
+/// state, then updates `sqrt_price_x64`/`liquidity` to match the post-trade
+/// reserves and credits `fee_rate`'s cut to `fee_growth_global_0/1_x64`, so
+/// LPs earn on stable-mode volume too.
+fn stable_swap_step(
+    pool_state: &mut std::cell::RefMut<PoolState>,
+    amount: u64,
+    is_base_input: bool,
+    zero_for_one: bool,
+    fee_rate: u32,
+) -> Result<(u64, u64)> {
+    let liquidity = pool_state.liquidity;
+    let reserves = virtual_reserves(liquidity, pool_state.sqrt_price_x64)?;
+
+    let result = compute_stable_swap(reserves, pool_state.amp_coefficient, amount, is_base_input, zero_for_one, fee_rate)?;
+
+    let (sqrt_price_x64, new_liquidity) = sqrt_price_and_liquidity_from_reserves(result.final_reserves)?;
+    pool_state.sqrt_price_x64 = sqrt_price_x64;
+    pool_state.tick_current = tick_math::get_tick_at_sqrt_price(sqrt_price_x64)?;
+    pool_state.liquidity = new_liquidity;
+
+    if liquidity > 0 {
+        let fee_growth_delta_x64 = (result.fee_amount as u128).saturating_mul(1u128 << 64) / liquidity;
+        if zero_for_one {
+            pool_state.fee_growth_global_0_x64 =
+                pool_state.fee_growth_global_0_x64.saturating_add(fee_growth_delta_x64);
+        } else {
+            pool_state.fee_growth_global_1_x64 =
+                pool_state.fee_growth_global_1_x64.saturating_add(fee_growth_delta_x64);
+        }
+    }
+
+    Ok((result.amount_in, result.amount_out))
+}
+
+/// Read-only counterpart to `stable_swap_step`, used by `quote_swap`/
+/// `quote_swap_router_base_in` when the pool is in `CurveMode::Stable` and
+/// within its peg band. Returns `(amount_in, amount_out, fee_amount,
+/// sqrt_price_x64, tick)` without mutating any account.
+pub fn stable_swap_quote(
+    liquidity: u128,
+    sqrt_price_x64: u128,
+    amp_coefficient: u64,
+    amount: u64,
+    is_base_input: bool,
+    zero_for_one: bool,
+    fee_rate: u32,
+) -> Result<(u64, u64, u64, u128, i32)> {
+    let reserves = virtual_reserves(liquidity, sqrt_price_x64)?;
+    let result = compute_stable_swap(reserves, amp_coefficient, amount, is_base_input, zero_for_one, fee_rate)?;
+    let (new_sqrt_price_x64, _) = sqrt_price_and_liquidity_from_reserves(result.final_reserves)?;
+    let tick = tick_math::get_tick_at_sqrt_price(new_sqrt_price_x64)?;
+    Ok((result.amount_in, result.amount_out, result.fee_amount, new_sqrt_price_x64, tick))
+}
+
+/// Read-only counterpart to `concentrated_swap_loop`, used by
+/// `quote_swap`/`quote_swap_router_base_in`. Walks the same tick arrays and
+/// runs the same `swap_math::compute_swap_step` path so a quote can never
+/// disagree with the swap it quotes, but never calls `load_mut` and never
+/// writes back liquidity_net/fill-accumulator changes — a quote must not
+/// mutate any account. Returns `(amount_in, amount_out, fee_amount,
+/// sqrt_price_x64, tick)`.
+pub fn traverse_readonly<'info>(
+    sqrt_price_x64: u128,
+    tick_current: i32,
+    liquidity: u128,
+    tick_spacing: u16,
+    tick_arrays: &[AccountInfo<'info>],
+    amount: u64,
+    sqrt_price_limit_x64: u128,
+    is_base_input: bool,
+    zero_for_one: bool,
+    fee_rate: u32,
+) -> Result<(u64, u64, u64, u128, i32)> {
+    let mut sqrt_price_x64 = sqrt_price_x64;
+    let mut tick = tick_current;
+    let mut liquidity = liquidity;
+
+    let mut amount_remaining = amount;
+    let mut amount_in_total: u64 = 0;
+    let mut amount_out_total: u64 = 0;
+    let mut fee_total: u64 = 0;
+
+    let sqrt_price_limit_x64 = if sqrt_price_limit_x64 == 0 {
+        if zero_for_one {
+            tick_math::get_sqrt_price_at_tick(tick_math::MIN_TICK)?
+        } else {
+            tick_math::get_sqrt_price_at_tick(tick_math::MAX_TICK)?
+        }
+    } else {
+        sqrt_price_limit_x64
+    };
+
+    for tick_array_info in tick_arrays {
+        if amount_remaining == 0 {
+            break;
+        }
+        let tick_array_loader: AccountLoader<TickArrayState> = AccountLoader::try_from(tick_array_info)?;
+        let tick_array = tick_array_loader.load()?;
+
+        loop {
+            if amount_remaining == 0 {
+                break;
+            }
+            let offset = match tick_array.next_initialized_tick_offset(tick, tick_spacing, zero_for_one) {
+                Some(o) => o,
+                None => break,
+            };
+            let tick_state = &tick_array.ticks[offset];
+            let tick_sqrt_price = tick_math::get_sqrt_price_at_tick(tick_state.tick)?;
+
+            let reached_limit = zero_for_one && tick_sqrt_price < sqrt_price_limit_x64
+                || !zero_for_one && tick_sqrt_price > sqrt_price_limit_x64;
+            let sqrt_price_target = if reached_limit { sqrt_price_limit_x64 } else { tick_sqrt_price };
+
+            let step = swap_math::compute_swap_step(
+                sqrt_price_x64,
+                sqrt_price_target,
+                liquidity,
+                amount_remaining,
+                fee_rate,
+                is_base_input,
+            )?;
+
+            sqrt_price_x64 = step.sqrt_price_next_x64;
+            amount_in_total = amount_in_total.saturating_add(step.amount_in).saturating_add(step.fee_amount);
+            amount_out_total = amount_out_total.saturating_add(step.amount_out);
+            fee_total = fee_total.saturating_add(step.fee_amount);
+            amount_remaining = if is_base_input {
+                amount_remaining.saturating_sub(step.amount_in + step.fee_amount)
+            } else {
+                amount_remaining.saturating_sub(step.amount_out)
+            };
+
+            if sqrt_price_x64 == tick_sqrt_price && !reached_limit {
+                let liquidity_net = if zero_for_one { -tick_state.liquidity_net } else { tick_state.liquidity_net };
+                liquidity = liquidity_math::add_delta(liquidity, liquidity_net)?;
+
+                let limit_liquidity = tick_state.limit_liquidity(zero_for_one);
+                if limit_liquidity > 0 && amount_remaining > 0 {
+                    let fill = fill_limit_liquidity(
+                        limit_liquidity,
+                        tick_sqrt_price,
+                        amount_remaining,
+                        is_base_input,
+                        zero_for_one,
+                        fee_rate,
+                    )?;
+
+                    if fill.consumed_output > 0 {
+                        amount_in_total = amount_in_total.saturating_add(fill.amount_in);
+                        amount_out_total = amount_out_total.saturating_add(fill.amount_out);
+                        fee_total = fee_total.saturating_add(
+                            u64::try_from(fill.limit_fee_amount).map_err(|_| error!(ErrorCode::LiquidityAddValueErr))?,
+                        );
+                        amount_remaining = amount_remaining.saturating_sub(fill.amount_remaining_consumed);
+                    }
+                }
+
+                tick = if zero_for_one { tick_state.tick - 1 } else { tick_state.tick };
+            } else {
+                tick = tick_math::get_tick_at_sqrt_price(sqrt_price_x64)?;
+                break;
+            }
+
+            if reached_limit {
+                break;
+            }
+        }
+        if sqrt_price_x64 == sqrt_price_limit_x64 {
+            break;
+        }
+    }
+
+    Ok((amount_in_total, amount_out_total, fee_total, sqrt_price_x64, tick))
+}
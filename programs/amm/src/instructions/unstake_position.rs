@@ -0,0 +1,119 @@
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct UnstakePosition<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_stake: Box<Account<'info, PoolStakeState>>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner,
+        constraint = position_stake.pool_id == pool_stake.pool_id,
+    )]
+    pub position_stake: Box<Account<'info, PositionStakeState>>,
+
+    #[account(mut)]
+    pub nft_account: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+
+    /// PDA signer authority over `nft_vault`, derived as
+    /// `[POOL_STAKE_SEED, pool_stake.pool_id, pool_stake.bump]`. The vault's
+    /// SPL authority is this PDA, not the vault account itself, so returning
+    /// the NFT has to sign through it like `claim_staking_reward` does for
+    /// `reward_vault`.
+    /// CHECK: verified by the `seeds`/`bump` constraint below, not read.
+    #[account(
+        seeds = [POOL_STAKE_SEED.as_bytes(), pool_stake.pool_id.as_ref()],
+        bump = pool_stake.bump,
+    )]
+    pub pool_stake_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Requests (or completes) an unstake. A withdrawal that would pull more than
+/// `pool_stake.withdrawal_pro_rate_threshold_bps` of the pool's total staked
+/// liquidity out at once is pro-rated linearly over
+/// `WITHDRAWAL_WINDOW_SECONDS`: the NFT itself is a single indivisible asset
+/// and still only moves on the call that completes the window, but the
+/// position's weight in the pool's accounting vests progressively on every
+/// call in between, via `withdrawal_liquidity_settled` - so a large stake
+/// mid-withdrawal stops diluting (and earning) new rewards for the fraction
+/// of itself that has already vested, rather than for its full original
+/// liquidity right up until the final call. Accrued swap fees on the
+/// underlying position are left in place; this tree has no
+/// `increase_liquidity`/`PersonalPositionState` fee-accounting implementation
+/// to auto-compound them into, so there is nothing real to wire here without
+/// inventing that subsystem from scratch.
+pub fn unstake_position(ctx: Context<UnstakePosition>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pool_stake = &mut ctx.accounts.pool_stake;
+    let position_stake = &mut ctx.accounts.position_stake;
+
+    pool_stake.settle_reward_growth(now);
+
+    let share_bps = if pool_stake.total_staked_liquidity == 0 {
+        10_000u128
+    } else {
+        (position_stake.liquidity as u128)
+            .saturating_mul(10_000)
+            .checked_div(pool_stake.total_staked_liquidity)
+            .unwrap_or(10_000)
+    };
+
+    let below_threshold = share_bps <= pool_stake.withdrawal_pro_rate_threshold_bps as u128;
+
+    if !below_threshold {
+        if !position_stake.unstake_requested {
+            position_stake.unstake_requested = true;
+            position_stake.unstake_requested_at = now;
+            position_stake.withdrawal_liquidity_settled = 0;
+        }
+
+        let vested_fraction_x64 = position_stake.withdrawal_vested_fraction_x64(now);
+        let vested_liquidity =
+            (position_stake.liquidity.saturating_mul(vested_fraction_x64) >> 64) as u128;
+        let newly_vested = vested_liquidity.saturating_sub(position_stake.withdrawal_liquidity_settled);
+        if newly_vested > 0 {
+            pool_stake.total_staked_liquidity =
+                pool_stake.total_staked_liquidity.saturating_sub(newly_vested);
+            position_stake.withdrawal_liquidity_settled = vested_liquidity;
+        }
+
+        if vested_fraction_x64 < (1u128 << 64) {
+            // Window still in progress; the vested share above is already
+            // folded out of the pool's accounting, but the NFT itself only
+            // moves once the window fully elapses.
+            return Ok(());
+        }
+    } else {
+        pool_stake.total_staked_liquidity = pool_stake
+            .total_staked_liquidity
+            .saturating_sub(position_stake.liquidity);
+    }
+
+    let pool_id = pool_stake.pool_id;
+    let bump = pool_stake.bump;
+    let authority_seeds: &[&[u8]] = &[POOL_STAKE_SEED.as_bytes(), pool_id.as_ref(), &[bump]];
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.nft_vault.to_account_info(),
+                to: ctx.accounts.nft_account.to_account_info(),
+                authority: ctx.accounts.pool_stake_authority.to_account_info(),
+            },
+            &[authority_seeds],
+        ),
+        1,
+    )?;
+
+    Ok(())
+}
@@ -75,9 +75,33 @@ pub mod amm_v3 {
     ///
     /// * `ctx`- The context of accounts
     /// * `sqrt_price_x64` - the initial sqrt price (amount_token_1 / amount_token_0) of the pool as a Q64.64
-    ///
-    pub fn create_pool(ctx: Context<CreatePool>, sqrt_price_x64: u128) -> Result<()> {
-        instructions::create_pool(ctx, sqrt_price_x64)
+    /// * `curve_mode` - `CurveMode::Concentrated` (0) for the standard tick
+    ///    math, or `CurveMode::Stable` (1) to additionally price swaps within
+    ///    `stable_band_bps` of `peg_redemption_rate_x64` against the
+    ///    amplified StableSwap invariant. Fixed for the lifetime of the pool.
+    /// * `amp_coefficient` - StableSwap amplification coefficient `A`,
+    ///    ignored when `curve_mode` is `Concentrated`
+    /// * `peg_redemption_rate_x64` - Q64.64 peg target (token_1 per
+    ///    token_0); 1<<64 for a 1:1 peg, or an LSD's redemption rate
+    /// * `stable_band_bps` - half-width, in bps of the peg, of the band the
+    ///    stable curve applies within before falling back to tick math
+    ///
+    pub fn create_pool(
+        ctx: Context<CreatePool>,
+        sqrt_price_x64: u128,
+        curve_mode: u8,
+        amp_coefficient: u64,
+        peg_redemption_rate_x64: u128,
+        stable_band_bps: u16,
+    ) -> Result<()> {
+        instructions::create_pool(
+            ctx,
+            sqrt_price_x64,
+            curve_mode,
+            amp_coefficient,
+            peg_redemption_rate_x64,
+            stable_band_bps,
+        )
     }
 
     /// Reset a pool sqrt price, only can be reset if the pool hasn't be used.
@@ -311,4 +335,160 @@ pub mod amm_v3 {
     ) -> Result<()> {
         instructions::swap_router_base_in(ctx, amount_in, amount_out_minimum)
     }
+
+    /// Opens a single-tick, one-directional limit order: a deposit of one token
+    /// at `tick` that fills completely into the other token the first time the
+    /// pool's sqrt price strictly crosses `tick`, rather than a continuously
+    /// rebalanced range position.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `tick` - The single tick the order rests at; must be a multiple of the
+    ///    pool's tick spacing and must not equal the pool's current tick
+    /// * `order_id` - Caller-chosen nonce, unique per `(pool, tick, order_owner)`,
+    ///    so more than one order can rest at the same tick
+    /// * `zero_for_one` - True to deposit token_1 and fill into token_0 as price
+    ///    crosses `tick` moving down; false for the opposite side
+    /// * `amount` - The amount of the input token to deposit
+    ///
+    pub fn open_limit_order(
+        ctx: Context<OpenLimitOrder>,
+        tick: i32,
+        order_id: u64,
+        zero_for_one: bool,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::open_limit_order(ctx, tick, order_id, zero_for_one, amount)
+    }
+
+    /// Closes a limit order, paying out any unfilled input plus any filled
+    /// output (plus its share of accrued fees), whether or not price has
+    /// crossed the order's tick yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    ///
+    pub fn close_limit_order(ctx: Context<CloseLimitOrder>) -> Result<()> {
+        instructions::close_limit_order(ctx)
+    }
+
+    /// Quotes a single-pool swap without mutating any account, walking the
+    /// same tick-array traversal as `swap`. Returned via CPI return data.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount` - Same meaning as in `swap`: amount in or amount out,
+    ///    depending on `is_base_input`
+    /// * `sqrt_price_limit_x64` - The Q64.64 sqrt price √P limit
+    /// * `is_base_input` - swap base input or swap base output
+    /// * `with_fees` - true to return the net, fee-inclusive amount; false for
+    ///    the gross pre-fee amount used for price-impact calculations
+    ///
+    pub fn quote_swap<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, QuoteSwap<'info>>,
+        amount: u64,
+        sqrt_price_limit_x64: u128,
+        is_base_input: bool,
+        with_fees: bool,
+    ) -> Result<SwapQuoteResult> {
+        instructions::quote_swap(ctx, amount, sqrt_price_limit_x64, is_base_input, with_fees)
+    }
+
+    /// Quotes a multi-hop, base-input swap across the route provided, without
+    /// mutating any account. Matching counterpart to `swap_router_base_in`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount_in` - Token amount that would be swapped in
+    /// * `zero_for_one` - Swap direction for each hop, in hop order; must have
+    ///    exactly one entry per pool in the route
+    /// * `with_fees` - true to return the net, fee-inclusive amount; false for
+    ///    the gross pre-fee amount used for price-impact calculations
+    ///
+    pub fn quote_swap_router_base_in<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, QuoteSwapRouterBaseIn<'info>>,
+        amount_in: u64,
+        zero_for_one: Vec<bool>,
+        with_fees: bool,
+    ) -> Result<SwapQuoteResult> {
+        instructions::quote_swap_router_base_in(ctx, amount_in, zero_for_one, with_fees)
+    }
+
+    /// Overrides the trade fee rate for a single pool, independent of the
+    /// shared `amm_config` its other pools use. Must be called by the config
+    /// owner/admin. Fee growth accumulators are already current as of the
+    /// pool's last swap, so the new rate only ever applies to volume that
+    /// trades after this instruction lands.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `trade_fee_rate` - The new trade fee rate, must be less than
+    ///    `FEE_RATE_DENOMINATOR_VALUE` and at most half of it
+    ///
+    pub fn set_pool_fee_rate(ctx: Context<SetPoolFeeRate>, trade_fee_rate: u32) -> Result<()> {
+        instructions::set_pool_fee_rate(ctx, trade_fee_rate)
+    }
+
+    /// Transfers a position NFT into the pool's staking vault so it starts
+    /// earning emissions on top of swap fees, weighted by its liquidity share
+    /// and how long it stays staked.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    ///
+    pub fn stake_position(ctx: Context<StakePosition>) -> Result<()> {
+        instructions::stake_position(ctx)
+    }
+
+    /// Requests, and once the withdrawal window has elapsed, completes an
+    /// unstake. Unstaking more than `pool_stake.withdrawal_pro_rate_threshold_bps`
+    /// of a pool's total staked liquidity at once is pro-rated linearly over
+    /// a withdrawal window rather than paid out immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    ///
+    pub fn unstake_position(ctx: Context<UnstakePosition>) -> Result<()> {
+        instructions::unstake_position(ctx)
+    }
+
+    /// Claims staking emissions accrued by a staked position since it was
+    /// staked or last claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    ///
+    pub fn claim_staking_reward(ctx: Context<ClaimStakingReward>) -> Result<()> {
+        instructions::claim_staking_reward(ctx)
+    }
+
+    /// Sets the dust thresholds `swap` checks its resulting token transfers
+    /// against, to reject trades so small they'd round to zero fee growth
+    /// while still touching real accounts. (`increase_liquidity`/
+    /// `decrease_liquidity` have no implementation in this tree to apply the
+    /// same check in yet.)
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `min_swap_amount_0` - Minimum accepted token_0 transfer, in
+    ///    token_0's smallest unit; 0 disables the check for this side
+    /// * `min_swap_amount_1` - Minimum accepted token_1 transfer, in
+    ///    token_1's smallest unit; 0 disables the check for this side
+    ///
+    pub fn set_pool_min_swap_amount(
+        ctx: Context<SetPoolMinSwapAmount>,
+        min_swap_amount_0: u64,
+        min_swap_amount_1: u64,
+    ) -> Result<()> {
+        instructions::set_pool_min_swap_amount(ctx, min_swap_amount_0, min_swap_amount_1)
+    }
 }
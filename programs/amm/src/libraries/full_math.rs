@@ -0,0 +1,69 @@
+/// `floor(a * b / denominator)`. There is no 256-bit intermediate, so an
+/// `a * b` overflowing u128 is not tolerated; the multiplication is `checked`, so
+/// it fails closed (returns `None`) instead of wrapping on the rare trade
+/// large enough to overflow, rather than silently producing a wrong amount.
+pub fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    a.checked_mul(b)?.checked_div(denominator)
+}
+
+/// `ceil(a * b / denominator)`.
+pub fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let numerator = a.checked_mul(b)?;
+    numerator
+        .checked_add(denominator.checked_sub(1)?)?
+        .checked_div(denominator)
+}
+
+/// `floor(a * b / 2^64)`, i.e. multiplying two Q64.64 fixed-point numbers.
+/// Plain `a.checked_mul(b)` overflows as soon as both operands are near the
+/// Q64.64 representation of 1 (~2^64), which is exactly the common case for
+/// prices/ratios near parity — so this widens the multiply by hand instead
+/// of going through `checked_mul` on the full operands.
+pub fn mul_q64(a: u128, b: u128) -> Option<u128> {
+    let a_hi = a >> 64;
+    let a_lo = a & u64::MAX as u128;
+    let b_hi = b >> 64;
+    let b_lo = b & u64::MAX as u128;
+
+    let term_hi_hi = a_hi.checked_mul(b_hi)?.checked_mul(1u128 << 64)?;
+    let term_hi_lo = a_hi.checked_mul(b_lo)?;
+    let term_lo_hi = a_lo.checked_mul(b_hi)?;
+    let term_lo_lo = a_lo.checked_mul(b_lo)? >> 64;
+
+    term_hi_hi
+        .checked_add(term_hi_lo)?
+        .checked_add(term_lo_hi)?
+        .checked_add(term_lo_lo)
+}
+
+/// Integer square root via Newton's method (floor of the real square root).
+pub fn sqrt_u128(x: u128) -> u128 {
+    if x == 0 {
+        return 0;
+    }
+    let mut z = x;
+    let mut y = (x >> 1) + 1;
+    while y < z {
+        z = y;
+        y = (x / y + y) >> 1;
+    }
+    z
+}
+
+pub fn div_rounding_up(numerator: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let quotient = numerator.checked_div(denominator)?;
+    if numerator % denominator == 0 {
+        Some(quotient)
+    } else {
+        quotient.checked_add(1)
+    }
+}
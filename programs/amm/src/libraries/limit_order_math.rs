@@ -0,0 +1,109 @@
+/// Q64.64 representation of "100% filled".
+pub const FILLED_ACCUM_ONE_X64: u128 = 1u128 << 64;
+
+/// Advance a tick's filled-fraction accumulator for one side by `filled_ratio_x64`
+/// (the portion of that side's resting limit liquidity consumed by this swap
+/// step, in Q64.64). Saturates at `FILLED_ACCUM_ONE_X64` so a tick that is
+/// crossed multiple times (e.g. oscillating price) never reports more than
+/// fully filled.
+pub fn advance_filled_accum(accum_x64: u128, filled_ratio_x64: u128) -> u128 {
+    accum_x64
+        .saturating_add(filled_ratio_x64)
+        .min(FILLED_ACCUM_ONE_X64)
+}
+
+/// A single order's pro-rata share of output/input given the tick-wide delta in
+/// the filled accumulator since the order was opened.
+///
+/// `liquidity` is the order's share of the tick's resting limit liquidity for
+/// its side; `accum_delta_x64` is `filled_fraction_x64` from
+/// `PersonalLimitOrderState`, already clamped to [0, FILLED_ACCUM_ONE_X64].
+pub fn filled_amount(liquidity: u128, accum_delta_x64: u128) -> u128 {
+    liquidity
+        .saturating_mul(accum_delta_x64.min(FILLED_ACCUM_ONE_X64))
+        >> 64
+}
+
+/// The unfilled remainder of the order's original deposit.
+pub fn unfilled_amount(amount_deposited: u64, liquidity: u128, accum_delta_x64: u128) -> u64 {
+    let filled_liquidity = filled_amount(liquidity, accum_delta_x64);
+    let unfilled_liquidity = liquidity.saturating_sub(filled_liquidity);
+    // liquidity and amount_deposited scale linearly for a single-sided deposit
+    if liquidity == 0 {
+        0
+    } else {
+        (u128::from(amount_deposited).saturating_mul(unfilled_liquidity) / liquidity) as u64
+    }
+}
+
+/// Converts a filled amount of the deposited side into the output side at the
+/// order's resting tick's price, rather than paying it out 1:1. `price_x64`
+/// is `sqrt_price_x64` squared (Q64.64 token_1-per-token_0) at that tick.
+///
+/// `zero_for_one` here matches `TickState::limit_liquidity`'s convention
+/// (the side that *fills*, i.e. the side `next_initialized_tick_offset` was
+/// walked with), not a swap's own input/output: a `zero_for_one` order rests
+/// as `limit_liquidity_token_1` and is paid out in token_0
+/// (`amount_out = amount_in / price`); a `!zero_for_one` order rests as
+/// `limit_liquidity_token_0` and is paid out in token_1
+/// (`amount_out = amount_in * price`).
+pub fn convert_at_tick_price(amount_in: u128, price_x64: u128, zero_for_one: bool) -> Option<u64> {
+    let amount_out = if zero_for_one {
+        crate::libraries::full_math::mul_div_floor(amount_in, 1u128 << 64, price_x64)?
+    } else {
+        crate::libraries::full_math::mul_div_floor(amount_in, price_x64, 1u128 << 64)?
+    };
+    u64::try_from(amount_out).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const Q64: u128 = 1u128 << 64;
+
+    #[test]
+    fn advance_filled_accum_saturates_at_one() {
+        assert_eq!(advance_filled_accum(0, Q64 / 2), Q64 / 2);
+        assert_eq!(advance_filled_accum(Q64 / 2, Q64 / 2), Q64);
+        assert_eq!(advance_filled_accum(Q64 / 2, Q64), Q64);
+    }
+
+    #[test]
+    fn filled_amount_is_pro_rata_share() {
+        let liquidity = 1_000u128;
+        assert_eq!(filled_amount(liquidity, 0), 0);
+        assert_eq!(filled_amount(liquidity, Q64), liquidity);
+        assert_eq!(filled_amount(liquidity, Q64 / 2), 500);
+    }
+
+    #[test]
+    fn unfilled_amount_tracks_deposit_pro_rata() {
+        assert_eq!(unfilled_amount(1_000, 1_000, Q64 / 4), 750);
+        assert_eq!(unfilled_amount(1_000, 1_000, Q64), 0);
+        assert_eq!(unfilled_amount(1_000, 1_000, 0), 1_000);
+    }
+
+    #[test]
+    fn convert_at_tick_price_zero_for_one_divides_by_price() {
+        // price_x64 = 2<<64 means 2 token_1 per token_0; a zero_for_one order
+        // rests in token_1 and is paid out in token_0, so amount_out = amount_in / price.
+        let price_x64 = 2 * Q64;
+        assert_eq!(convert_at_tick_price(1_000, price_x64, true), Some(500));
+    }
+
+    #[test]
+    fn convert_at_tick_price_one_for_zero_multiplies_by_price() {
+        // a !zero_for_one order rests in token_0 and is paid out in token_1,
+        // so amount_out = amount_in * price.
+        let price_x64 = 2 * Q64;
+        assert_eq!(convert_at_tick_price(1_000, price_x64, false), Some(2_000));
+    }
+
+    #[test]
+    fn convert_at_tick_price_round_trips_at_unit_price() {
+        let price_x64 = Q64;
+        assert_eq!(convert_at_tick_price(777, price_x64, true), Some(777));
+        assert_eq!(convert_at_tick_price(777, price_x64, false), Some(777));
+    }
+}
@@ -0,0 +1,19 @@
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Applies a signed liquidity delta (as crossed from `TickState::liquidity_net`,
+/// or requested by `increase_liquidity`/`decrease_liquidity`) to `liquidity`.
+pub fn add_delta(liquidity: u128, delta: i128) -> Result<u128> {
+    if delta == 0 {
+        return Ok(liquidity);
+    }
+    if delta > 0 {
+        liquidity
+            .checked_add(delta as u128)
+            .ok_or(error!(ErrorCode::LiquidityAddValueErr))
+    } else {
+        liquidity
+            .checked_sub((-delta) as u128)
+            .ok_or(error!(ErrorCode::LiquiditySubValueErr))
+    }
+}
@@ -0,0 +1,10 @@
+pub mod full_math;
+pub mod limit_order_math;
+pub mod liquidity_math;
+pub mod sqrt_price_math;
+pub mod stable_curve;
+pub mod swap_math;
+pub mod tick_math;
+
+pub use limit_order_math::*;
+pub use liquidity_math::*;
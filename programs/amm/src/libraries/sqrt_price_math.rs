@@ -0,0 +1,103 @@
+use crate::error::ErrorCode;
+use crate::libraries::full_math;
+use anchor_lang::prelude::*;
+
+const RESOLUTION: u32 = 64;
+
+/// `token_0` delta between two sqrt prices for `liquidity`:
+/// `L * (sqrtB - sqrtA) / (sqrtA * sqrtB)`, computed as
+/// `mulDiv(L << 64, sqrtB - sqrtA, sqrtB) / sqrtA` to avoid the intermediate
+/// `sqrtA * sqrtB` product, the same reordering Uniswap's `SqrtPriceMath` uses.
+pub fn get_amount_0_delta(
+    sqrt_price_a_x64: u128,
+    sqrt_price_b_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u64> {
+    let (sqrt_a, sqrt_b) = if sqrt_price_a_x64 <= sqrt_price_b_x64 {
+        (sqrt_price_a_x64, sqrt_price_b_x64)
+    } else {
+        (sqrt_price_b_x64, sqrt_price_a_x64)
+    };
+    if sqrt_a == 0 {
+        return Err(error!(ErrorCode::SqrtPriceLimitOverflow));
+    }
+
+    let numerator1 = liquidity
+        .checked_shl(RESOLUTION)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    let numerator2 = sqrt_b - sqrt_a;
+
+    let amount = if round_up {
+        full_math::div_rounding_up(
+            full_math::mul_div_ceil(numerator1, numerator2, sqrt_b)
+                .ok_or(error!(ErrorCode::LiquidityAddValueErr))?,
+            sqrt_a,
+        )
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?
+    } else {
+        full_math::mul_div_floor(numerator1, numerator2, sqrt_b)
+            .ok_or(error!(ErrorCode::LiquidityAddValueErr))?
+            / sqrt_a
+    };
+    u64::try_from(amount).map_err(|_| error!(ErrorCode::LiquidityAddValueErr))
+}
+
+/// `token_1` delta between two sqrt prices for `liquidity`:
+/// `L * (sqrtB - sqrtA)`.
+pub fn get_amount_1_delta(
+    sqrt_price_a_x64: u128,
+    sqrt_price_b_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u64> {
+    let (sqrt_a, sqrt_b) = if sqrt_price_a_x64 <= sqrt_price_b_x64 {
+        (sqrt_price_a_x64, sqrt_price_b_x64)
+    } else {
+        (sqrt_price_b_x64, sqrt_price_a_x64)
+    };
+    let diff = sqrt_b - sqrt_a;
+
+    let amount = if round_up {
+        full_math::mul_div_ceil(liquidity, diff, 1u128 << RESOLUTION)
+    } else {
+        full_math::mul_div_floor(liquidity, diff, 1u128 << RESOLUTION)
+    }
+    .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    u64::try_from(amount).map_err(|_| error!(ErrorCode::LiquidityAddValueErr))
+}
+
+/// Next sqrt price after adding `amount` of token_0 as exact input.
+pub fn get_next_sqrt_price_from_amount_0_rounding_up(
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    amount: u64,
+) -> Result<u128> {
+    if amount == 0 {
+        return Ok(sqrt_price_x64);
+    }
+    let numerator1 = liquidity
+        .checked_shl(RESOLUTION)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    let product = (amount as u128)
+        .checked_mul(sqrt_price_x64)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    let denominator = numerator1
+        .checked_add(product)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    full_math::mul_div_ceil(numerator1, sqrt_price_x64, denominator)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))
+}
+
+/// Next sqrt price after adding `amount` of token_1 as exact input.
+pub fn get_next_sqrt_price_from_amount_1_rounding_down(
+    sqrt_price_x64: u128,
+    liquidity: u128,
+    amount: u64,
+) -> Result<u128> {
+    let quotient = full_math::mul_div_floor(amount as u128, 1u128 << RESOLUTION, liquidity)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))?;
+    sqrt_price_x64
+        .checked_add(quotient)
+        .ok_or(error!(ErrorCode::LiquidityAddValueErr))
+}
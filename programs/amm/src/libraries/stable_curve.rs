@@ -0,0 +1,126 @@
+/// Newton's-method iteration count; converges well before this for any
+/// realistic reserve ratio, matching the common StableSwap reference
+/// implementations.
+const NEWTON_ITERATIONS: u8 = 255;
+const CONVERGENCE_EPSILON: u128 = 1;
+
+/// Solves the StableSwap invariant
+/// `A·n^n·Σx + D = A·D·n^n + D^(n+1) / (n^n·Πx)`
+/// for `D` given reserves `amounts` (n = 2 for this pool's token_0/token_1)
+/// and amplification coefficient `amp`.
+pub fn compute_d(amounts: [u128; 2], amp: u64) -> Option<u128> {
+    let n = amounts.len() as u128;
+    let sum = amounts[0].checked_add(amounts[1])?;
+    if sum == 0 {
+        return Some(0);
+    }
+    let ann = (amp as u128).checked_mul(n)?;
+
+    let mut d = sum;
+    for _ in 0..NEWTON_ITERATIONS {
+        // d_p = D^(n+1) / (n^n * Pi(x))
+        let mut d_p = d;
+        for amount in amounts.iter() {
+            d_p = d_p.checked_mul(d)?.checked_div(amount.checked_mul(n)?)?;
+        }
+        let d_prev = d;
+        let numerator = ann
+            .checked_mul(sum)?
+            .checked_add(d_p.checked_mul(n)?)?
+            .checked_mul(d)?;
+        let denominator = ann
+            .checked_sub(1)?
+            .checked_mul(d)?
+            .checked_add(d_p.checked_mul(n + 1)?)?;
+        d = numerator.checked_div(denominator)?;
+
+        if d > d_prev {
+            if d - d_prev <= CONVERGENCE_EPSILON {
+                break;
+            }
+        } else if d_prev - d <= CONVERGENCE_EPSILON {
+            break;
+        }
+    }
+    Some(d)
+}
+
+/// Solves for the new balance of `amounts[out_index]` that keeps the
+/// invariant `D` constant, given every other entry of `amounts` already holds
+/// its post-swap value. Caller updates `amounts[in_index]` to reflect the
+/// swap input before calling this; `out_index` is the only balance being
+/// solved for, so it is read here purely to know which entry to exclude from
+/// the accumulated product/sum (for n=2 that's the one other index, but the
+/// loop below generalizes to any pool size).
+pub fn compute_y(amounts: [u128; 2], amp: u64, out_index: usize, d: u128) -> Option<u128> {
+    let n = amounts.len() as u128;
+    let ann = (amp as u128).checked_mul(n)?;
+
+    // c = D^(n+1) / (n^n * Pi(x_j, j != out_index)); b = Sum(x_j, j != out_index) + D/Ann.
+    let mut c = d;
+    let mut sum_known = 0u128;
+    for (j, amount) in amounts.iter().enumerate() {
+        if j == out_index {
+            continue;
+        }
+        c = c.checked_mul(d)?.checked_div(amount.checked_mul(n)?)?;
+        sum_known = sum_known.checked_add(*amount)?;
+    }
+    c = c.checked_mul(d)?.checked_div(ann.checked_mul(n)?)?;
+    let b = sum_known.checked_add(d.checked_div(ann)?)?;
+
+    let mut y = d;
+    for _ in 0..NEWTON_ITERATIONS {
+        let y_prev = y;
+        let numerator = y.checked_mul(y)?.checked_add(c)?;
+        let denominator = (y.checked_mul(2)?).checked_add(b)?.checked_sub(d)?;
+        y = numerator.checked_div(denominator)?;
+
+        if y > y_prev {
+            if y - y_prev <= CONVERGENCE_EPSILON {
+                break;
+            }
+        } else if y_prev - y <= CONVERGENCE_EPSILON {
+            break;
+        }
+    }
+    Some(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_d_is_the_sum_for_balanced_reserves() {
+        // At perfect balance the invariant degenerates to D = sum(x).
+        let d = compute_d([1_000_000, 1_000_000], 100).unwrap();
+        assert!((d as i128 - 2_000_000i128).abs() <= 2);
+    }
+
+    #[test]
+    fn compute_y_recovers_the_untouched_reserve() {
+        let amounts = [1_000_000u128, 2_000_000u128];
+        let d = compute_d(amounts, 100).unwrap();
+
+        // Solving for the reserve that's already in `amounts` should return
+        // (approximately) itself, since nothing actually changed.
+        let y = compute_y(amounts, 100, 1, d).unwrap();
+        assert!((y as i128 - amounts[1] as i128).abs() <= 2);
+    }
+
+    #[test]
+    fn compute_y_reflects_a_deposit_on_the_other_side() {
+        let amounts = [1_000_000u128, 1_000_000u128];
+        let d = compute_d(amounts, 100).unwrap();
+
+        // Depositing into side 0 should let side 1 settle lower while
+        // keeping the invariant D constant.
+        let deposited = [amounts[0] + 100_000, amounts[1]];
+        let new_side_1 = compute_y(deposited, 100, 1, d).unwrap();
+        assert!(new_side_1 < amounts[1]);
+
+        let recovered_d = compute_d([deposited[0], new_side_1], 100).unwrap();
+        assert!((recovered_d as i128 - d as i128).abs() <= 2);
+    }
+}
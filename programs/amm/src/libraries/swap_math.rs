@@ -0,0 +1,164 @@
+use crate::error::ErrorCode;
+use crate::libraries::{full_math, sqrt_price_math};
+use anchor_lang::prelude::*;
+
+/// Result of a single tick-array traversal step, shared by the mutating
+/// `swap` instruction and the read-only `quote_swap` instruction so the two
+/// code paths can never drift apart.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct SwapStep {
+    pub sqrt_price_next_x64: u128,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+}
+
+/// Computes a single swap step between `sqrt_price_current_x64` and
+/// `sqrt_price_target_x64` (the nearer of the next initialized tick or the
+/// caller's price limit), charging `fee_rate` (parts of
+/// `FEE_RATE_DENOMINATOR_VALUE`) against the input amount. This is the exact
+/// function both `instructions::swap` (mutating) and `quote_swap`/
+/// `quote_swap_router_base_in` (read-only) call per tick-array step, so a
+/// quote can never disagree with the swap it quotes.
+pub fn compute_swap_step(
+    sqrt_price_current_x64: u128,
+    sqrt_price_target_x64: u128,
+    liquidity: u128,
+    amount_remaining: u64,
+    fee_rate: u32,
+    is_base_input: bool,
+) -> Result<SwapStep> {
+    let zero_for_one = sqrt_price_current_x64 >= sqrt_price_target_x64;
+    const FEE_RATE_DENOMINATOR_VALUE: u128 = 1_000_000;
+
+    let mut step = SwapStep::default();
+
+    if is_base_input {
+        let amount_remaining_less_fee = full_math::mul_div_floor(
+            amount_remaining as u128,
+            (FEE_RATE_DENOMINATOR_VALUE as u128)
+                .checked_sub(fee_rate as u128)
+                .ok_or(error!(ErrorCode::FeeRateOverflow))?,
+            FEE_RATE_DENOMINATOR_VALUE,
+        )
+        .ok_or(error!(ErrorCode::FeeRateOverflow))?;
+
+        let max_amount_in = if zero_for_one {
+            sqrt_price_math::get_amount_0_delta(
+                sqrt_price_target_x64,
+                sqrt_price_current_x64,
+                liquidity,
+                true,
+            )?
+        } else {
+            sqrt_price_math::get_amount_1_delta(
+                sqrt_price_current_x64,
+                sqrt_price_target_x64,
+                liquidity,
+                true,
+            )?
+        };
+
+        if amount_remaining_less_fee >= max_amount_in as u128 {
+            step.sqrt_price_next_x64 = sqrt_price_target_x64;
+            step.amount_in = max_amount_in;
+        } else {
+            step.amount_in = amount_remaining_less_fee as u64;
+            step.sqrt_price_next_x64 = if zero_for_one {
+                sqrt_price_math::get_next_sqrt_price_from_amount_0_rounding_up(
+                    sqrt_price_current_x64,
+                    liquidity,
+                    step.amount_in,
+                )?
+            } else {
+                sqrt_price_math::get_next_sqrt_price_from_amount_1_rounding_down(
+                    sqrt_price_current_x64,
+                    liquidity,
+                    step.amount_in,
+                )?
+            };
+        }
+
+        step.amount_out = if zero_for_one {
+            sqrt_price_math::get_amount_1_delta(
+                step.sqrt_price_next_x64,
+                sqrt_price_current_x64,
+                liquidity,
+                false,
+            )?
+        } else {
+            sqrt_price_math::get_amount_0_delta(
+                sqrt_price_current_x64,
+                step.sqrt_price_next_x64,
+                liquidity,
+                false,
+            )?
+        };
+
+        step.fee_amount = if step.sqrt_price_next_x64 != sqrt_price_target_x64 {
+            // Stopped short of the target: the whole remaining budget is
+            // spent, so the fee is whatever of it wasn't needed as input.
+            amount_remaining.saturating_sub(step.amount_in)
+        } else {
+            full_math::mul_div_ceil(
+                step.amount_in as u128,
+                fee_rate as u128,
+                (FEE_RATE_DENOMINATOR_VALUE as u128)
+                    .checked_sub(fee_rate as u128)
+                    .ok_or(error!(ErrorCode::FeeRateOverflow))?,
+            )
+            .ok_or(error!(ErrorCode::FeeRateOverflow))? as u64
+        };
+    } else {
+        let max_amount_out = if zero_for_one {
+            sqrt_price_math::get_amount_1_delta(
+                sqrt_price_target_x64,
+                sqrt_price_current_x64,
+                liquidity,
+                false,
+            )?
+        } else {
+            sqrt_price_math::get_amount_0_delta(
+                sqrt_price_current_x64,
+                sqrt_price_target_x64,
+                liquidity,
+                false,
+            )?
+        };
+
+        if amount_remaining >= max_amount_out {
+            step.sqrt_price_next_x64 = sqrt_price_target_x64;
+            step.amount_out = max_amount_out;
+        } else {
+            step.amount_out = amount_remaining;
+            step.sqrt_price_next_x64 = sqrt_price_target_x64;
+        }
+
+        step.amount_in = if zero_for_one {
+            sqrt_price_math::get_amount_0_delta(
+                step.sqrt_price_next_x64,
+                sqrt_price_current_x64,
+                liquidity,
+                true,
+            )?
+        } else {
+            sqrt_price_math::get_amount_1_delta(
+                sqrt_price_current_x64,
+                step.sqrt_price_next_x64,
+                liquidity,
+                true,
+            )?
+        };
+
+        step.fee_amount = full_math::mul_div_ceil(
+            step.amount_in as u128,
+            fee_rate as u128,
+            (FEE_RATE_DENOMINATOR_VALUE as u128)
+                .checked_sub(fee_rate as u128)
+                .ok_or(error!(ErrorCode::FeeRateOverflow))?,
+        )
+        .ok_or(error!(ErrorCode::FeeRateOverflow))? as u64;
+    }
+
+    Ok(step)
+}
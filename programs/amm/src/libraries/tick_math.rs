@@ -0,0 +1,58 @@
+use crate::error::ErrorCode;
+use crate::libraries::full_math;
+use anchor_lang::prelude::*;
+
+pub const MIN_TICK: i32 = -443636;
+pub const MAX_TICK: i32 = 443636;
+
+/// Q64.64 `sqrt(1.0001)`, the per-tick price ratio's square root: moving one
+/// tick up multiplies the sqrt price by this constant.
+const TICK_SQRT_RATIO_X64: u128 = 0x1000346d6ff11672a;
+/// Q64.64 `1 / sqrt(1.0001)`, used for negative ticks.
+const TICK_SQRT_RATIO_INV_X64: u128 = 0xfffcb933bd6fad37;
+
+const Q64: u128 = 1u128 << 64;
+
+fn mul_q64(a: u128, b: u128) -> Result<u128> {
+    full_math::mul_q64(a, b).ok_or(error!(ErrorCode::TickOutOfRange))
+}
+
+/// `sqrt(1.0001)^tick` as a Q64.64, computed by square-and-multiply so only
+/// two base constants are needed instead of one precomputed constant per bit
+/// of tick (the approach libraries like Uniswap's `TickMath` use purely to
+/// save gas) — same result, fewer magic numbers to get wrong.
+pub fn get_sqrt_price_at_tick(tick: i32) -> Result<u128> {
+    require!(tick >= MIN_TICK && tick <= MAX_TICK, ErrorCode::TickOutOfRange);
+
+    let (mut base, mut exp) = if tick >= 0 {
+        (TICK_SQRT_RATIO_X64, tick as u32)
+    } else {
+        (TICK_SQRT_RATIO_INV_X64, (-tick) as u32)
+    };
+
+    let mut result: u128 = Q64;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_q64(result, base)?;
+        }
+        base = mul_q64(base, base)?;
+        exp >>= 1;
+    }
+    Ok(result)
+}
+
+/// Inverse of `get_sqrt_price_at_tick` by binary search; `get_sqrt_price_at_tick`
+/// is monotonic in `tick` so this always converges.
+pub fn get_tick_at_sqrt_price(sqrt_price_x64: u128) -> Result<i32> {
+    let mut lo = MIN_TICK;
+    let mut hi = MAX_TICK;
+    while lo < hi {
+        let mid = lo + (hi - lo + 1) / 2;
+        if get_sqrt_price_at_tick(mid)? <= sqrt_price_x64 {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    Ok(lo)
+}
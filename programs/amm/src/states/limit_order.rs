@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+pub const LIMIT_ORDER_SEED: &str = "limit_order";
+pub const LIMIT_ORDER_NFT_MINT_SEED: &str = "limit_order_nft_mint";
+
+/// A single-tick, one-directional limit order. Unlike `PersonalPositionState`,
+/// which straddles a price range and is continuously rebalanced, this is a pure
+/// one-sided deposit at `tick` that fills completely (and only once) when the
+/// pool's sqrt price strictly crosses `tick` in the direction implied by
+/// `zero_for_one`.
+#[account]
+#[derive(Default, Debug)]
+pub struct PersonalLimitOrderState {
+    /// The only account allowed to close this order (and redirect its
+    /// payout/NFT). Checked via `has_one` in `close_limit_order`, independent
+    /// of who currently holds `nft_mint` — the NFT is a transferable receipt,
+    /// but closing always pays out to the account on record here.
+    pub owner: Pubkey,
+    /// Mint of the NFT representing ownership of this limit order.
+    pub nft_mint: Pubkey,
+    pub pool_id: Pubkey,
+    /// The single tick this order rests at. Must be a multiple of the pool's
+    /// tick spacing and must not equal the pool's tick at the time of opening.
+    pub tick: i32,
+    /// True if the order deposits token_1 and fills into token_0 once price
+    /// crosses `tick` moving down; false for the opposite side. Matches
+    /// `TickState::limit_liquidity`'s convention, not a swap's own
+    /// input/output side.
+    pub zero_for_one: bool,
+    /// The order's share of the tick's limit liquidity for its side.
+    pub liquidity: u128,
+    /// Input token amount deposited at open.
+    pub amount_deposited: u64,
+    /// Snapshot of the tick's `limit_order_filled_accum_*_x64` at open time, used
+    /// to compute this order's filled fraction as the delta since then.
+    pub filled_accum_x64_at_open: u128,
+    /// Fee growth inside snapshot at open, reused for the pro-rated fee share
+    /// paid out on close, following the same accounting as range positions.
+    pub fee_growth_inside_last_x64: u128,
+    pub closed: bool,
+}
+
+impl PersonalLimitOrderState {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 4 + 1 + 16 + 8 + 16 + 16 + 1;
+
+    /// Fraction filled in [0, 1<<64], clamped because `current_accum` may equal
+    /// `filled_accum_x64_at_open` (order placed after the tick started filling
+    /// makes no retroactive claim on liquidity that filled before it joined).
+    pub fn filled_fraction_x64(&self, current_accum_x64: u128) -> u128 {
+        current_accum_x64.saturating_sub(self.filled_accum_x64_at_open)
+    }
+}
@@ -0,0 +1,140 @@
+pub mod limit_order;
+pub mod stake;
+pub mod swap_quote;
+pub mod tick_array;
+
+pub use limit_order::*;
+pub use stake::*;
+pub use swap_quote::*;
+pub use tick_array::*;
+
+use anchor_lang::prelude::*;
+
+/// Seed for the pool PDA. `PoolState` signs CPIs out of its own token vaults
+/// (it is their SPL `authority`) using these seeds plus its stored `bump`.
+pub const POOL_SEED: &str = "pool";
+
+/// Minimal surface of the pool account referenced by the limit-order,
+/// quoting, and fee-override instructions; it holds no reward infos or
+/// tick bitmap.
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+pub struct PoolState {
+    /// Bump of this account's own `[POOL_SEED, amm_config]` PDA, stored so it
+    /// can sign vault transfers via `CpiContext::new_with_signer` without
+    /// re-deriving it off-chain.
+    pub bump: u8,
+    pub amm_config: Pubkey,
+    pub token_vault_0: Pubkey,
+    pub token_vault_1: Pubkey,
+    pub tick_spacing: u16,
+    pub tick_current: i32,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub fee_growth_global_0_x64: u128,
+    pub fee_growth_global_1_x64: u128,
+    /// When `trade_fee_rate_override_set` is true, this overrides the trade
+    /// fee rate a pool's `amm_config` would otherwise apply, set via
+    /// `set_pool_fee_rate`. Parts of `FEE_RATE_DENOMINATOR_VALUE`.
+    pub trade_fee_rate_override: u32,
+    pub trade_fee_rate_override_set: bool,
+    /// Snapshot of `fee_growth_global_0/1_x64` taken by `set_pool_fee_rate`
+    /// the moment an override takes effect, so downstream consumers (reward
+    /// accounting, indexers) can split fee income earned under the old rate
+    /// from income earned under the new one without replaying swap history.
+    pub fee_growth_global_0_x64_at_last_rate_change: u128,
+    pub fee_growth_global_1_x64_at_last_rate_change: u128,
+
+    /// Curve used for swap pricing; see `CurveMode`. Fixed at `create_pool`
+    /// time since switching curves mid-flight would invalidate standing
+    /// positions' accounting.
+    pub curve_mode: u8,
+    /// StableSwap amplification coefficient `A`. Unused when `curve_mode` is
+    /// `CurveMode::Concentrated`.
+    pub amp_coefficient: u64,
+    /// Q64.64 peg target (token_1 per token_0) the stable band is centered
+    /// on. 1<<64 for a 1:1 peg; for LSD/underlying pairs this is the LSD's
+    /// redemption rate instead, refreshed out of band (not by this program).
+    /// Only the band check reads it; the StableSwap pricing itself values
+    /// the two virtual reserves 1:1 and does not scale them by this rate.
+    pub peg_redemption_rate_x64: u128,
+    /// Half-width, in basis points of the peg, of the band within which the
+    /// stable curve is used. The band is checked against the pool's price
+    /// before the swap only: a swap starting inside the band is priced wholly
+    /// on the stable curve, even if it moves price outside the band.
+    pub stable_band_bps: u16,
+
+    /// Minimum `amount_0` transfer `swap` will accept, in token_0's
+    /// smallest unit (`increase_liquidity`/`decrease_liquidity` do not check
+    /// it). Set by
+    /// the config owner with the token's decimals in mind; 0 disables the
+    /// check for this side. Guards against dust transfers that round to zero
+    /// fee growth or zero liquidity delta while still charging/crediting
+    /// real accounts.
+    pub min_swap_amount_0: u64,
+    /// Same as `min_swap_amount_0`, for token_1.
+    pub min_swap_amount_1: u64,
+}
+
+/// Selects which invariant `swap` prices against for a pool.
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CurveMode {
+    /// The existing pure concentrated-liquidity `x*y=k` tick math.
+    Concentrated = 0,
+    /// Amplified StableSwap invariant for correlated/pegged pairs, used
+    /// within `stable_band_bps` of `peg_redemption_rate_x64` and falling
+    /// back to `Concentrated` outside of it.
+    Stable = 1,
+}
+
+impl PoolState {
+    /// Whether a swap starting at `sqrt_price_x64` should be priced with the
+    /// StableSwap invariant rather than the concentrated tick math. Called
+    /// once per trade by `instructions::swap` (and the quote instructions)
+    /// with the pool's current price, before any pricing is done.
+    pub fn should_use_stable_curve(&self, sqrt_price_x64: u128) -> bool {
+        if self.curve_mode != CurveMode::Stable as u8 {
+            return false;
+        }
+        // price_x64 = sqrt_price_x64^2 / 2^64, compared against the peg
+        // target within +/- stable_band_bps/10000. `sqrt_price_x64` is itself
+        // Q64.64 and near parity is ~2^64, so squaring it directly overflows
+        // u128 before the shift ever divides it back down — `mul_q64` widens
+        // the multiply by hand instead of truncating/saturating it away.
+        let price_x64 = match crate::libraries::full_math::mul_q64(sqrt_price_x64, sqrt_price_x64) {
+            Some(p) => p,
+            None => return false,
+        };
+        let band = (self.peg_redemption_rate_x64 / 10_000).saturating_mul(self.stable_band_bps as u128);
+        price_x64 >= self.peg_redemption_rate_x64.saturating_sub(band)
+            && price_x64 <= self.peg_redemption_rate_x64.saturating_add(band)
+    }
+
+    /// The rate `swap` should actually charge: the pool's own override when
+    /// `set_pool_fee_rate` has set one, otherwise the shared `amm_config`
+    /// rate.
+    pub fn effective_trade_fee_rate(&self, amm_config_trade_fee_rate: u32) -> u32 {
+        if self.trade_fee_rate_override_set {
+            self.trade_fee_rate_override
+        } else {
+            amm_config_trade_fee_rate
+        }
+    }
+
+    /// Rejects a token transfer amount that falls below the pool's dust
+    /// threshold for that side. `swap` calls it with its post-fee
+    /// `amount_0`/`amount_1` before moving tokens; `increase_liquidity` and
+    /// `decrease_liquidity` do not call it. A side whose amount is legitimately zero (e.g. a
+    /// single-sided liquidity add) is not flagged, only a nonzero dust
+    /// amount is.
+    pub fn check_min_swap_amount(&self, amount_0: u64, amount_1: u64) -> Result<()> {
+        if amount_0 > 0 && amount_0 < self.min_swap_amount_0 {
+            return Err(crate::error::ErrorCode::AmountBelowMinimum.into());
+        }
+        if amount_1 > 0 && amount_1 < self.min_swap_amount_1 {
+            return Err(crate::error::ErrorCode::AmountBelowMinimum.into());
+        }
+        Ok(())
+    }
+}
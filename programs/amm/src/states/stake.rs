@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+
+pub const STAKE_SEED: &str = "position_stake";
+pub const POOL_STAKE_SEED: &str = "pool_stake";
+
+/// Withdrawal window a stake above `withdrawal_pro_rate_threshold_bps` of a
+/// pool's total staked liquidity is linearly paid out over, to discourage
+/// timing the unstake around a large incoming reward drop.
+pub const WITHDRAWAL_WINDOW_SECONDS: i64 = 14 * 24 * 60 * 60;
+
+/// Pool-wide staking accounting, one per pool that has staking enabled.
+/// Mirrors the role `PoolState.fee_growth_global_0/1_x64` plays for swap
+/// fees, but for the NFT-position staking incentive layer.
+#[account]
+#[derive(Default, Debug)]
+pub struct PoolStakeState {
+    pub pool_id: Pubkey,
+    /// Bump of this account's own `[POOL_STAKE_SEED, pool_id]` authority PDA,
+    /// stored so `claim_staking_reward`/`unstake_position` can sign
+    /// `reward_vault`/`nft_vault` transfers via `CpiContext::new_with_signer`
+    /// without re-deriving it off-chain.
+    pub bump: u8,
+    /// Current total liquidity staked across every open `PositionStakeState`
+    /// for this pool - the real-time stock `reward_growth_global_x64`
+    /// accrues against, kept current on every `stake`/`unstake`.
+    pub total_staked_liquidity: u128,
+    /// Above this fraction (parts per 10_000) of `total_staked_liquidity`,
+    /// a single unstake is pro-rated over `WITHDRAWAL_WINDOW_SECONDS` instead
+    /// of paid out immediately.
+    pub withdrawal_pro_rate_threshold_bps: u16,
+    /// Reward growth accumulator (Q64.64, per unit of staked liquidity) for
+    /// the staking reward vault. Accrues each `claim_staking_reward` call as
+    /// `emitted_since_last_update / total_staked_liquidity` - a position that
+    /// has been staked since an earlier growth snapshot captures every
+    /// interval's delta since then, so duration weighting falls out of the
+    /// lazy per-position settlement rather than needing its own accumulator.
+    pub reward_growth_global_x64: u128,
+    pub last_update_time: i64,
+    pub reward_vault: Pubkey,
+    pub emissions_per_second_x64: u128,
+}
+
+impl PoolStakeState {
+    /// Brings `reward_growth_global_x64` current against `total_staked_liquidity`
+    /// as it stands *right now*, before that stock changes. Called from
+    /// `stake_position`/`unstake_position` (ahead of adjusting
+    /// `total_staked_liquidity`) as well as `claim_staking_reward`, so no
+    /// interval's emission is ever divided by a stock that didn't actually
+    /// apply for its whole duration.
+    pub fn settle_reward_growth(&mut self, now: i64) {
+        if self.total_staked_liquidity > 0 && self.emissions_per_second_x64 > 0 {
+            let elapsed = now.saturating_sub(self.last_update_time).max(0) as u128;
+            let emitted_x64 = self.emissions_per_second_x64.saturating_mul(elapsed);
+            self.reward_growth_global_x64 = self
+                .reward_growth_global_x64
+                .saturating_add(emitted_x64 / self.total_staked_liquidity);
+        }
+        self.last_update_time = now;
+    }
+}
+
+/// A single staked position. Created when a position NFT is transferred into
+/// the pool-owned vault via `stake_position`.
+#[account]
+#[derive(Default, Debug)]
+pub struct PositionStakeState {
+    pub nft_mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub owner: Pubkey,
+    /// The position's liquidity at stake time; this is what
+    /// `reward_growth_global_x64`'s delta is multiplied against, so longer a
+    /// position sits staked, the larger a growth delta it collects on claim.
+    pub liquidity: u128,
+    pub staked_at: i64,
+    pub reward_growth_inside_last_x64: u128,
+    /// Set when an unstake above the pro-rate threshold is requested; the
+    /// withdrawal completes linearly between this timestamp and
+    /// `unstake_requested_at + WITHDRAWAL_WINDOW_SECONDS`.
+    pub unstake_requested_at: i64,
+    pub unstake_requested: bool,
+    /// Portion of `liquidity` already folded out of
+    /// `pool_stake.total_staked_liquidity` by a pending unstake's vesting
+    /// progress; rises toward `liquidity` as `withdrawal_vested_fraction_x64`
+    /// climbs toward 1.0, so a withdrawal in progress stops counting (and
+    /// diluting future rewards for) the share of itself that has already
+    /// vested, rather than all-or-nothing at the end of the window.
+    pub withdrawal_liquidity_settled: u128,
+}
+
+impl PositionStakeState {
+    /// Fraction of a pro-rated unstake that has vested linearly over the
+    /// withdrawal window, in Q64.64.
+    pub fn withdrawal_vested_fraction_x64(&self, now: i64) -> u128 {
+        if !self.unstake_requested {
+            return 1u128 << 64;
+        }
+        let elapsed = now.saturating_sub(self.unstake_requested_at).max(0);
+        if elapsed >= WITHDRAWAL_WINDOW_SECONDS {
+            return 1u128 << 64;
+        }
+        ((elapsed as u128) << 64) / (WITHDRAWAL_WINDOW_SECONDS as u128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_staker_first_claim_collects_the_full_emission() {
+        let mut pool_stake = PoolStakeState {
+            total_staked_liquidity: 1_000,
+            emissions_per_second_x64: 1u128 << 64,
+            last_update_time: 0,
+            ..Default::default()
+        };
+
+        // A lone staker owns the entire stock, so the very first settlement
+        // (no prior claim) must still divide by that stock, not by zero.
+        pool_stake.settle_reward_growth(10);
+        assert_eq!(pool_stake.reward_growth_global_x64, 10u128 << 64);
+
+        let mut position = PositionStakeState {
+            liquidity: 1_000,
+            reward_growth_inside_last_x64: 0,
+            ..Default::default()
+        };
+        let growth_delta = pool_stake
+            .reward_growth_global_x64
+            .saturating_sub(position.reward_growth_inside_last_x64);
+        let reward = (position.liquidity.saturating_mul(growth_delta) >> 64) as u64;
+        position.reward_growth_inside_last_x64 = pool_stake.reward_growth_global_x64;
+
+        // 10 seconds * 1 token/sec emitted, all owned by the one staker.
+        assert_eq!(reward, 10);
+    }
+
+    #[test]
+    fn reward_converges_to_fair_share_as_the_stock_changes() {
+        let mut pool_stake = PoolStakeState {
+            total_staked_liquidity: 1_000,
+            emissions_per_second_x64: 1u128 << 64,
+            last_update_time: 0,
+            ..Default::default()
+        };
+        let mut a = PositionStakeState { liquidity: 1_000, ..Default::default() };
+
+        // A alone for the first 10 seconds.
+        pool_stake.settle_reward_growth(10);
+
+        // B joins with equal size; settling first keeps the first interval's
+        // emission divided by the stock that actually applied during it.
+        pool_stake.total_staked_liquidity = pool_stake.total_staked_liquidity.saturating_add(1_000);
+        let mut b = PositionStakeState {
+            liquidity: 1_000,
+            reward_growth_inside_last_x64: pool_stake.reward_growth_global_x64,
+            ..Default::default()
+        };
+
+        // A and B split the next 10 seconds evenly.
+        pool_stake.settle_reward_growth(20);
+
+        let claim = |position: &mut PositionStakeState, pool_stake: &PoolStakeState| -> u64 {
+            let delta = pool_stake
+                .reward_growth_global_x64
+                .saturating_sub(position.reward_growth_inside_last_x64);
+            position.reward_growth_inside_last_x64 = pool_stake.reward_growth_global_x64;
+            (position.liquidity.saturating_mul(delta) >> 64) as u64
+        };
+
+        let reward_a = claim(&mut a, &pool_stake);
+        let reward_b = claim(&mut b, &pool_stake);
+
+        // A earns the first 10 seconds solo plus half of the next 10; B only
+        // earns half of the next 10. Total emitted across 20s is 20 tokens.
+        assert_eq!(reward_a, 15);
+        assert_eq!(reward_b, 5);
+        assert_eq!(reward_a + reward_b, 20);
+    }
+
+    #[test]
+    fn withdrawal_vests_linearly_then_completes() {
+        let position = PositionStakeState {
+            unstake_requested: true,
+            unstake_requested_at: 0,
+            ..Default::default()
+        };
+
+        assert_eq!(position.withdrawal_vested_fraction_x64(0), 0);
+        assert_eq!(
+            position.withdrawal_vested_fraction_x64(WITHDRAWAL_WINDOW_SECONDS / 2),
+            1u128 << 63
+        );
+        assert_eq!(
+            position.withdrawal_vested_fraction_x64(WITHDRAWAL_WINDOW_SECONDS),
+            1u128 << 64
+        );
+        assert_eq!(
+            position.withdrawal_vested_fraction_x64(WITHDRAWAL_WINDOW_SECONDS * 2),
+            1u128 << 64
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Result of `quote_swap`/`quote_swap_router_base_in`. Returned via Anchor's
+/// CPI return-data mechanism (the instruction touches no `mut` accounts, so it
+/// is safe to call from a `simulateTransaction`) so routers get exact,
+/// execution-consistent numbers without sending a real swap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct SwapQuoteResult {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub sqrt_price_x64: u128,
+    pub tick: i32,
+}
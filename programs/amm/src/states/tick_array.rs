@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+/// Number of ticks held in a single tick array account.
+pub const TICK_ARRAY_SIZE: usize = 60;
+
+pub const TICK_ARRAY_SEED: &str = "tick_array";
+
+#[account(zero_copy(unsafe))]
+#[repr(packed)]
+pub struct TickArrayState {
+    pub pool_id: Pubkey,
+    pub start_tick_index: i32,
+    pub ticks: [TickState; TICK_ARRAY_SIZE],
+    pub initialized_tick_count: u8,
+    pub padding: [u8; 115],
+}
+
+impl TickArrayState {
+    pub fn get_tick_offset_in_array(&self, tick_index: i32, tick_spacing: u16) -> Result<usize> {
+        let offset_in_array =
+            (tick_index - self.start_tick_index) / i32::from(tick_spacing);
+        Ok(offset_in_array as usize)
+    }
+
+    /// Scans this array, in the direction of travel, for the next tick with
+    /// either range liquidity (`liquidity_gross > 0`) or resting limit-order
+    /// liquidity on either side. `zero_for_one == true` searches downward
+    /// (price falling) from `current_tick` itself, since price sits at or
+    /// above it; otherwise the search starts strictly above `current_tick`.
+    pub fn next_initialized_tick_offset(
+        &self,
+        current_tick: i32,
+        tick_spacing: u16,
+        zero_for_one: bool,
+    ) -> Option<usize> {
+        let current_offset = (current_tick - self.start_tick_index).div_euclid(i32::from(tick_spacing));
+        if zero_for_one {
+            (0..=current_offset.min(TICK_ARRAY_SIZE as i32 - 1)).rev().find_map(|i| {
+                let i = i as usize;
+                let t = &self.ticks[i];
+                (t.liquidity_gross > 0 || t.limit_liquidity_token_0 > 0 || t.limit_liquidity_token_1 > 0)
+                    .then_some(i)
+            })
+        } else {
+            ((current_offset + 1).max(0) as usize..TICK_ARRAY_SIZE).find(|&i| {
+                let t = &self.ticks[i];
+                t.liquidity_gross > 0 || t.limit_liquidity_token_0 > 0 || t.limit_liquidity_token_1 > 0
+            })
+        }
+    }
+}
+
+/// Per-tick accounting. `liquidity_net`/`liquidity_gross` track the continuously
+/// rebalanced range positions opened via `open_position`; the `limit_*` fields
+/// below track single-sided limit-order liquidity deposited via
+/// `open_limit_order`, which is consumed in one direction only as price crosses
+/// the tick and never re-enters range accounting.
+#[zero_copy(unsafe)]
+#[repr(packed)]
+#[derive(Default, Debug)]
+pub struct TickState {
+    pub tick: i32,
+    /// Net liquidity change when the tick is crossed going left to right (range positions)
+    pub liquidity_net: i128,
+    /// Total range position liquidity referencing this tick as a boundary
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_0_x64: u128,
+    pub fee_growth_outside_1_x64: u128,
+    pub reward_growths_outside_x64: [u128; 3],
+
+    /// Limit-order liquidity resting at this tick, denominated in token_0, that
+    /// fills (converts to token_1) once the pool's sqrt price crosses the tick
+    /// moving up (zero_for_one == false).
+    pub limit_liquidity_token_0: u128,
+    /// Limit-order liquidity resting at this tick, denominated in token_1, that
+    /// fills (converts to token_0) once the pool's sqrt price crosses the tick
+    /// moving down (zero_for_one == true).
+    pub limit_liquidity_token_1: u128,
+    /// Monotonically increasing "fraction filled" accumulator (Q64.64, saturates
+    /// at 1<<64 meaning 100% filled) for the token_0-side limit liquidity resting
+    /// at this tick. A limit order's filled amount is its pro-rata liquidity share
+    /// times the delta of this accumulator between open and collect.
+    pub limit_order_filled_accum_0_x64: u128,
+    /// Same as above, for the token_1-side limit liquidity resting at this tick.
+    pub limit_order_filled_accum_1_x64: u128,
+
+    /// Fee growth (Q64.64, in token_0, per unit of `limit_liquidity_token_1`)
+    /// earned by a fill: when a zero_for_one=true order is filled, the
+    /// swapper pays the pool's trade fee on top of the order's payout, same
+    /// as a range-liquidity swap step would, and that fee is credited here
+    /// rather than to `fee_growth_global_0_x64` since it's funded by (and
+    /// owed to) the resting limit liquidity specifically.
+    pub limit_fee_growth_0_x64: u128,
+    /// Same as above, in token_1, per unit of `limit_liquidity_token_0`,
+    /// earned when a zero_for_one=false order is filled.
+    pub limit_fee_growth_1_x64: u128,
+}
+
+impl TickState {
+    /// Limit liquidity resting on the given side of this tick.
+    pub fn limit_liquidity(&self, zero_for_one: bool) -> u128 {
+        if zero_for_one {
+            self.limit_liquidity_token_1
+        } else {
+            self.limit_liquidity_token_0
+        }
+    }
+
+    pub fn limit_filled_accum(&self, zero_for_one: bool) -> u128 {
+        if zero_for_one {
+            self.limit_order_filled_accum_1_x64
+        } else {
+            self.limit_order_filled_accum_0_x64
+        }
+    }
+
+    /// Fee growth owed, per unit of limit liquidity, to orders resting on
+    /// the given side - denominated in whichever token that side is paid
+    /// out in (token_0 for zero_for_one=true, token_1 for zero_for_one=false).
+    pub fn limit_fee_growth(&self, zero_for_one: bool) -> u128 {
+        if zero_for_one {
+            self.limit_fee_growth_0_x64
+        } else {
+            self.limit_fee_growth_1_x64
+        }
+    }
+}